@@ -1,42 +1,18 @@
-extern crate rand;
+use mazes::algorithms::*;
+use mazes::grid::*;
+use mazes::rng;
 
-mod grid;
-
-use crate::grid::*;
-
-fn binary_tree_decide_link(cell: Option<&Cell>) -> Option<Coordinates> {
-    cell.map_or(None, |cell| {
-        let options = vec![cell.north.clone(), cell.east.clone()]
-            .into_iter()
-            .flat_map(|x| x)
-            .collect::<Vec<Coordinates>>();
-
-
-        if options.len() == 0 {
-            None
-        } else {
-            let idx = rand::random::<usize>() % options.len();
-            Some(options[idx].clone()) // why can't move?
-        }
-    })
-}
-
-fn binary_tree(grid: &mut Grid) {
-    for row in 0..grid.rows {
-        for col in 0..grid.cols {
-            let cell_pos = Coordinates::new(row, col);
-
-            let linked = binary_tree_decide_link(grid.get(&cell_pos));
-
-            if linked.is_some() {
-                grid.link(cell_pos, linked.unwrap());
-            }
-        }
-    }
-}
+use std::env;
+use std::process;
 
 fn main() {
+    let seed = rng::parse_seed(env::args().nth(1).as_deref()).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+
     let mut grid = Grid::new(10,10);
-    binary_tree(&mut grid);
+    generate_seeded(&BinaryTree::default(), &mut grid, seed);
     println!("{}", grid);
+    println!("seed: {}", seed);
 }
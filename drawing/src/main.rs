@@ -1,45 +1,36 @@
-extern crate rand;
-extern crate image;
-extern crate imageproc;
-
-use imageproc::drawing::*;
-
-mod grid;
-
-use crate::grid::*;
+use mazes::algorithms::*;
+use mazes::distances::{longest_path, Distances};
+use mazes::grid::*;
+use mazes::render::png::{draw, RenderOptions};
+use mazes::render::svg::{save_svg, SvgOptions};
+use mazes::render::tiles::draw_tiles;
+use mazes::rng;
+
+use std::env;
 use std::path::Path;
+use std::process;
 
-fn sidewinder(grid: &mut Grid) {
-    for row in 0..grid.rows {
-        let mut run_start : Option<isize> = None;
-        for col in 0..grid.cols {
-            run_start = Some(run_start.unwrap_or(col));
-            let go_east = if col >= grid.cols-1 { false } else {row >= grid.rows - 1 || rand::random::<bool>()};
-
-            let cell_pos = Coordinates::new(row, col);
-            if go_east {
-                let east_pos = grid.get(&cell_pos).map(|x|x.east.clone().unwrap()).unwrap();
-                grid.link(cell_pos, east_pos);
-                continue;
-            }
+fn main() {
+    let seed = rng::parse_seed(env::args().nth(1).as_deref()).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
 
-            if row >= grid.rows - 1 { continue; }
+    let mut grid = Grid::new(30, 30);
+    generate_seeded(&Sidewinder::default(), &mut grid, seed);
+    println!("{}", grid);
+    println!("seed: {}", seed);
 
-            // Close the run
-            let run_length = col as usize - run_start.unwrap() as usize + 1;
-            let south_col = run_start.unwrap() + (rand::random::<usize>() % run_length) as isize;
-            let from_pos = Coordinates::new(row, south_col);
+    let options = RenderOptions::default();
+    draw(&grid, Path::new("/tmp/a.png"), &options, None, None).unwrap();
+    save_svg(&grid, Path::new("/tmp/a.svg"), &SvgOptions::default()).unwrap();
+    draw_tiles(&grid, Path::new("/tmp/a_tiles.png"), &RenderOptions { cell_size: 8, ..options.clone() }).unwrap();
 
-            let to_pos = grid.get(&from_pos).map(|x|x.south.clone().unwrap()).unwrap();
-            grid.link(from_pos, to_pos);
-            run_start = None;
-        }
-    }
-}
+    // Answer key, from one end of the longest path to the other.
+    let solution = longest_path(&grid).map(|longest| longest.path);
+    draw(&grid, Path::new("/tmp/a_solution.png"), &options, solution.as_deref(), None).unwrap();
 
-fn main() {
-    let mut grid = Grid::new(30, 30);
-    sidewinder(&mut grid);
-    println!("{}", grid);
-    let img = draw(&grid, Path::new("/tmp/a.png"));
+    let center = Coordinates::new(grid.rows / 2, grid.cols / 2);
+    let distances = Distances::new(&grid, &center);
+    draw(&grid, Path::new("/tmp/a_heat.png"), &options, None, distances.as_ref()).unwrap();
 }
@@ -0,0 +1,40 @@
+use rand::{Rng, RngCore};
+
+use crate::algorithms::MazeGenerator;
+use crate::grid::*;
+use crate::rng::choose;
+
+pub struct AldousBroder;
+
+impl MazeGenerator for AldousBroder {
+    fn name(&self) -> &'static str {
+        "aldous_broder"
+    }
+
+    fn generate(&self, grid: &mut Grid, rng: &mut dyn RngCore) {
+        aldous_broder(grid, rng);
+    }
+}
+
+// Random walk that links each cell the first time the walk enters it, which
+// yields a uniform spanning tree (every possible maze is equally likely).
+pub fn aldous_broder<R: Rng + ?Sized>(grid: &mut Grid, rng: &mut R) {
+    if grid.size() == 0 {
+        return;
+    }
+
+    let mut cell_pos = grid.random_cell(rng);
+    let mut unvisited = grid.size() - 1;
+
+    while unvisited > 0 {
+        let neighbors = grid.get(&cell_pos).unwrap().neighbors();
+        let neighbor_pos = choose(rng, &neighbors).unwrap().clone();
+
+        if grid.get(&neighbor_pos).unwrap().links.is_empty() {
+            grid.link(cell_pos, neighbor_pos.clone());
+            unvisited -= 1;
+        }
+
+        cell_pos = neighbor_pos;
+    }
+}
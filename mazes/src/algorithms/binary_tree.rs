@@ -0,0 +1,75 @@
+use rand::{Rng, RngCore};
+
+use crate::algorithms::MazeGenerator;
+use crate::grid::*;
+
+// The pair of directions every cell picks from. The two walls on the opposite
+// sides of the grid end up as long unbroken corridors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Diagonal {
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
+}
+
+impl Diagonal {
+    fn options(self, cell: &Cell) -> (Option<Coordinates>, Option<Coordinates>) {
+        match self {
+            Diagonal::NorthEast => (cell.north.clone(), cell.east.clone()),
+            Diagonal::NorthWest => (cell.north.clone(), cell.west.clone()),
+            Diagonal::SouthEast => (cell.south.clone(), cell.east.clone()),
+            Diagonal::SouthWest => (cell.south.clone(), cell.west.clone()),
+        }
+    }
+}
+
+// `vertical_probability` is the chance a cell with both options links north or
+// south rather than east or west.
+pub struct BinaryTree {
+    pub diagonal: Diagonal,
+    pub vertical_probability: f64,
+}
+
+impl Default for BinaryTree {
+    fn default() -> BinaryTree {
+        BinaryTree { diagonal: Diagonal::NorthEast, vertical_probability: 0.5 }
+    }
+}
+
+impl MazeGenerator for BinaryTree {
+    fn name(&self) -> &'static str {
+        "binary_tree"
+    }
+
+    fn generate(&self, grid: &mut Grid, rng: &mut dyn RngCore) {
+        binary_tree_with(grid, self.diagonal, self.vertical_probability, rng);
+    }
+}
+
+fn binary_tree_decide_link<R: Rng + ?Sized>(cell: Option<&Cell>, diagonal: Diagonal, vertical_probability: f64, rng: &mut R) -> Option<Coordinates> {
+    cell.and_then(|cell| {
+        match diagonal.options(cell) {
+            (Some(vertical), Some(horizontal)) => {
+                if rng.gen_bool(vertical_probability) { Some(vertical) } else { Some(horizontal) }
+            }
+            (vertical, horizontal) => vertical.or(horizontal),
+        }
+    })
+}
+
+pub fn binary_tree<R: Rng + ?Sized>(grid: &mut Grid, rng: &mut R) {
+    binary_tree_with(grid, Diagonal::NorthEast, 0.5, rng);
+}
+
+pub fn binary_tree_with<R: Rng + ?Sized>(grid: &mut Grid, diagonal: Diagonal, vertical_probability: f64, rng: &mut R) {
+    for row in 0..grid.rows {
+        for col in 0..grid.cols {
+            let cell_pos = Coordinates::new(row, col);
+
+            if let Some(linked) = binary_tree_decide_link(grid.get(&cell_pos), diagonal, vertical_probability, rng) {
+                grid.link(cell_pos, linked);
+            }
+        }
+    }
+}
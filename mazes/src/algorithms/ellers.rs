@@ -0,0 +1,123 @@
+use rand::{Rng, RngCore};
+
+use std::collections::{HashMap, HashSet};
+
+use crate::algorithms::MazeGenerator;
+use crate::grid::*;
+use crate::rng::random_index;
+
+pub struct Ellers;
+
+impl MazeGenerator for Ellers {
+    fn name(&self) -> &'static str {
+        "ellers"
+    }
+
+    fn generate(&self, grid: &mut Grid, rng: &mut dyn RngCore) {
+        for row in ellers_rows(grid.rows, grid.cols, rng) {
+            for col in 0..grid.cols {
+                let cell_pos = Coordinates::new(row.row, col);
+                if row.east[col as usize] {
+                    grid.link(cell_pos.clone(), Coordinates::new(row.row, col + 1));
+                }
+                if row.south[col as usize] {
+                    grid.link(cell_pos, Coordinates::new(row.row + 1, col));
+                }
+            }
+        }
+    }
+}
+
+pub fn ellers_rows<R: Rng + ?Sized>(rows: isize, cols: isize, rng: &mut R) -> EllersRows<'_, R> {
+    EllersRows { rng, rows, cols, row: 0, sets: vec![None; cols.max(0) as usize], members: HashMap::new(), next_set: 0 }
+}
+
+// Eller's algorithm as an iterator over rows. Only the set membership of the
+// current row is kept, so arbitrarily tall mazes can be generated (and streamed
+// to a renderer) in memory proportional to the width.
+pub struct EllersRows<'a, R: Rng + ?Sized> {
+    rng: &'a mut R,
+    rows: isize,
+    cols: isize,
+    row: isize,
+    // Set of each cell in the current row; `None` for cells not reached from above.
+    sets: Vec<Option<usize>>,
+    // Columns of the current row in each set, so merging only touches the smaller set.
+    members: HashMap<usize, Vec<usize>>,
+    next_set: usize,
+}
+
+impl<'a, R: Rng + ?Sized> EllersRows<'a, R> {
+    fn merge(&mut self, a: usize, b: usize) {
+        let (from, into) = if self.members[&a].len() < self.members[&b].len() { (a, b) } else { (b, a) };
+        let moved = self.members.remove(&from).unwrap();
+        for &col in &moved {
+            self.sets[col] = Some(into);
+        }
+        self.members.get_mut(&into).unwrap().extend(moved);
+    }
+}
+
+impl<'a, R: Rng + ?Sized> Iterator for EllersRows<'a, R> {
+    type Item = RowLinks;
+
+    fn next(&mut self) -> Option<RowLinks> {
+        if self.row >= self.rows {
+            return None;
+        }
+
+        let cols = self.cols as usize;
+        let last_row = self.row == self.rows - 1;
+
+        self.members.clear();
+        for (col, set) in self.sets.iter_mut().enumerate() {
+            if set.is_none() {
+                *set = Some(self.next_set);
+                self.next_set += 1;
+            }
+            self.members.entry(set.unwrap()).or_default().push(col);
+        }
+
+        // The last row has to join every set that is still separate.
+        let mut east = Vec::with_capacity(cols);
+        for col in 0..cols.saturating_sub(1) {
+            let (here, there) = (self.sets[col].unwrap(), self.sets[col + 1].unwrap());
+            let link = here != there && (last_row || self.rng.gen::<bool>());
+            if link {
+                self.merge(there, here);
+            }
+            east.push(link);
+        }
+        east.resize(cols, false);
+
+        // Every set needs at least one passage south so it isn't cut off.
+        let mut south = vec![false; cols];
+        if !last_row {
+            // Visit sets in order of their leftmost cell so a seed always draws
+            // the same random numbers for the same cells.
+            let mut done = HashSet::new();
+            for col in 0..cols {
+                let set = self.sets[col].unwrap();
+                if !done.insert(set) {
+                    continue;
+                }
+                let cells = self.members.get_mut(&set).unwrap();
+                cells.sort_unstable();
+                let forced = cells[random_index(self.rng, cells.len())];
+                for &col in cells.iter() {
+                    south[col] = col == forced || self.rng.gen_bool(1.0 / 3.0);
+                }
+            }
+        }
+
+        for (set, &goes_south) in self.sets.iter_mut().zip(&south) {
+            if !goes_south {
+                *set = None;
+            }
+        }
+
+        let row = RowLinks { row: self.row, east, south };
+        self.row += 1;
+        Some(row)
+    }
+}
@@ -0,0 +1,94 @@
+use rand::{Rng, RngCore};
+
+use std::collections::VecDeque;
+
+use crate::algorithms::MazeGenerator;
+use crate::grid::*;
+use crate::rng::{choose, random_index};
+
+// Which active cell the growing tree continues from on each step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CellSelection {
+    // Most recently added cell; behaves like the recursive backtracker.
+    Newest,
+    // Uniformly random active cell; behaves like simplified Prim's.
+    Random,
+    // Least recently added cell; produces long straight corridors.
+    Oldest,
+    // Newest with the given probability (0.0 to 1.0), otherwise random.
+    Mix { newest: f64 },
+}
+
+impl CellSelection {
+    fn select<R: Rng + ?Sized>(&self, rng: &mut R, len: usize) -> usize {
+        match *self {
+            CellSelection::Newest => len - 1,
+            CellSelection::Random => random_index(rng, len),
+            CellSelection::Oldest => 0,
+            CellSelection::Mix { newest } => {
+                if rng.gen_bool(newest) { len - 1 } else { random_index(rng, len) }
+            }
+        }
+    }
+}
+
+pub struct GrowingTree {
+    pub selection: CellSelection,
+}
+
+impl Default for GrowingTree {
+    fn default() -> GrowingTree {
+        GrowingTree { selection: CellSelection::Mix { newest: 0.5 } }
+    }
+}
+
+impl MazeGenerator for GrowingTree {
+    fn name(&self) -> &'static str {
+        "growing_tree"
+    }
+
+    fn generate(&self, grid: &mut Grid, rng: &mut dyn RngCore) {
+        growing_tree(grid, self.selection, rng);
+    }
+}
+
+pub fn growing_tree<R: Rng + ?Sized>(grid: &mut Grid, selection: CellSelection, rng: &mut R) {
+    if grid.size() == 0 {
+        return;
+    }
+
+    let mut visited = vec![false; grid.size()];
+    let start = grid.random_cell(rng);
+    visited[grid.get_index(&start).unwrap()] = true;
+    let mut active = VecDeque::new();
+    active.push_back(start);
+
+    while !active.is_empty() {
+        let selected = selection.select(rng, active.len());
+        let cell_pos = active[selected].clone();
+
+        let unvisited = grid.get(&cell_pos)
+            .unwrap()
+            .neighbors()
+            .into_iter()
+            .filter(|n| !visited[grid.get_index(n).unwrap()])
+            .collect::<Vec<_>>();
+
+        match choose(rng, &unvisited) {
+            Some(next) => {
+                let next = next.clone();
+                visited[grid.get_index(&next).unwrap()] = true;
+                grid.link(cell_pos, next.clone());
+                active.push_back(next);
+            }
+            None => {
+                // Order only matters to the newest/oldest strategies.
+                if selection == CellSelection::Random {
+                    active.swap_remove_back(selected);
+                } else {
+                    active.remove(selected);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use rand::{Rng, RngCore};
+
+use crate::algorithms::MazeGenerator;
+use crate::grid::*;
+use crate::rng::choose;
+
+pub struct HuntAndKill;
+
+impl MazeGenerator for HuntAndKill {
+    fn name(&self) -> &'static str {
+        "hunt_and_kill"
+    }
+
+    fn generate(&self, grid: &mut Grid, rng: &mut dyn RngCore) {
+        hunt_and_kill(grid, rng);
+    }
+}
+
+fn partition_neighbors(grid: &Grid, pos: &Coordinates) -> (Vec<Coordinates>, Vec<Coordinates>) {
+    grid.get(pos)
+        .unwrap()
+        .neighbors()
+        .into_iter()
+        .partition(|n| grid.get(n).unwrap().links.is_empty())
+}
+
+// Random walk that only steps onto unvisited cells. When it gets stuck, scan the
+// grid for the first unvisited cell bordering the maze, link it in and carry on
+// walking from there.
+pub fn hunt_and_kill<R: Rng + ?Sized>(grid: &mut Grid, rng: &mut R) {
+    if grid.size() == 0 {
+        return;
+    }
+
+    // Every cell before this index is already part of the maze, so the hunt
+    // doesn't need to rescan them.
+    let mut hunt_from = 0;
+    let mut current = Some(grid.random_cell(rng));
+
+    while let Some(cell_pos) = current {
+        let (unvisited, _) = partition_neighbors(grid, &cell_pos);
+        if let Some(next) = choose(rng, &unvisited) {
+            let next = next.clone();
+            grid.link(cell_pos, next.clone());
+            current = Some(next);
+            continue;
+        }
+
+        current = None;
+        while hunt_from < grid.size() && !grid.grid[hunt_from].links.is_empty() {
+            hunt_from += 1;
+        }
+
+        for idx in hunt_from..grid.size() {
+            if !grid.grid[idx].links.is_empty() {
+                continue;
+            }
+
+            let pos = grid.grid[idx].coordinates.clone();
+            let (_, visited) = partition_neighbors(grid, &pos);
+            if let Some(neighbor) = choose(rng, &visited) {
+                grid.link(pos.clone(), neighbor.clone());
+                current = Some(pos);
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use rand::{Rng, RngCore};
+
+use crate::algorithms::MazeGenerator;
+use crate::grid::*;
+use crate::rng::shuffle;
+
+pub struct Kruskals;
+
+impl MazeGenerator for Kruskals {
+    fn name(&self) -> &'static str {
+        "kruskals"
+    }
+
+    fn generate(&self, grid: &mut Grid, rng: &mut dyn RngCore) {
+        kruskals(grid, rng);
+    }
+}
+
+// Disjoint sets over cell indices, as computed by `Grid::get_index`.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    pub fn new(len: usize) -> UnionFind {
+        UnionFind { parent: (0..len).collect(), size: vec![1; len] }
+    }
+
+    // Starts with every passage already linked on `grid` merged.
+    pub fn from_grid(grid: &Grid) -> UnionFind {
+        let mut sets = UnionFind::new(grid.size());
+        for (idx, cell) in grid.grid.iter().enumerate() {
+            for link in &cell.links {
+                sets.union(idx, grid.get_index(link).unwrap());
+            }
+        }
+        sets
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    pub fn find(&mut self, mut idx: usize) -> usize {
+        while self.parent[idx] != idx {
+            self.parent[idx] = self.parent[self.parent[idx]];
+            idx = self.parent[idx];
+        }
+        idx
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    // Returns false if `a` and `b` were already in the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        true
+    }
+}
+
+// Links `pos1` and `pos2` unless they are already connected, which would close
+// a loop. Use this to force passages (crossings, rooms) before `kruskals_with_sets`.
+pub fn link_if_disjoint(grid: &mut Grid, sets: &mut UnionFind, pos1: Coordinates, pos2: Coordinates) -> bool {
+    let idx1 = grid.get_index(&pos1).unwrap();
+    let idx2 = grid.get_index(&pos2).unwrap();
+    if !sets.union(idx1, idx2) {
+        return false;
+    }
+    grid.link(pos1, pos2);
+    true
+}
+
+pub fn kruskals<R: Rng + ?Sized>(grid: &mut Grid, rng: &mut R) {
+    let mut sets = UnionFind::from_grid(grid);
+    kruskals_with_sets(grid, &mut sets, rng);
+}
+
+// Visits every pair of neighboring cells in random order and links the ones
+// that are still in different sets.
+pub fn kruskals_with_sets<R: Rng + ?Sized>(grid: &mut Grid, sets: &mut UnionFind, rng: &mut R) {
+    let mut edges = Vec::new();
+    for (idx, cell) in grid.grid.iter().enumerate() {
+        for neighbor in cell.neighbors() {
+            if grid.get_index(&neighbor).unwrap() > idx {
+                edges.push((cell.coordinates.clone(), neighbor));
+            }
+        }
+    }
+
+    shuffle(rng, &mut edges);
+
+    for (pos1, pos2) in edges {
+        link_if_disjoint(grid, sets, pos1, pos2);
+    }
+}
@@ -0,0 +1,67 @@
+use rand::RngCore;
+
+use crate::grid::Grid;
+use crate::rng;
+
+pub mod aldous_broder;
+pub mod binary_tree;
+pub mod ellers;
+pub mod growing_tree;
+pub mod hunt_and_kill;
+pub mod kruskals;
+pub mod prims;
+pub mod recursive_backtracker;
+pub mod recursive_division;
+pub mod sidewinder;
+pub mod wilsons;
+
+pub use self::aldous_broder::AldousBroder;
+pub use self::binary_tree::{BinaryTree, Diagonal};
+pub use self::ellers::Ellers;
+pub use self::growing_tree::{CellSelection, GrowingTree};
+pub use self::hunt_and_kill::HuntAndKill;
+pub use self::kruskals::Kruskals;
+pub use self::prims::{SimplifiedPrims, TruePrims};
+pub use self::recursive_backtracker::RecursiveBacktracker;
+pub use self::recursive_division::RecursiveDivision;
+pub use self::sidewinder::Sidewinder;
+pub use self::wilsons::Wilsons;
+
+// The RNG is passed as a trait object (rather than `impl Rng`) so generators
+// can be stored in the registry as `Box<dyn MazeGenerator>`.
+pub trait MazeGenerator {
+    fn name(&self) -> &'static str;
+    fn generate(&self, grid: &mut Grid, rng: &mut dyn RngCore);
+}
+
+pub fn generators() -> Vec<Box<dyn MazeGenerator>> {
+    vec![
+        Box::new(BinaryTree::default()),
+        Box::new(Sidewinder::default()),
+        Box::new(AldousBroder),
+        Box::new(Wilsons),
+        Box::new(HuntAndKill),
+        Box::new(RecursiveBacktracker),
+        Box::new(Kruskals),
+        Box::new(SimplifiedPrims),
+        Box::new(TruePrims),
+        Box::new(GrowingTree::default()),
+        Box::new(Ellers),
+        Box::new(RecursiveDivision::default()),
+    ]
+}
+
+pub fn generator_names() -> Vec<&'static str> {
+    generators().iter().map(|g| g.name()).collect()
+}
+
+pub fn find_generator(name: &str) -> Option<Box<dyn MazeGenerator>> {
+    generators().into_iter().find(|g| g.name().eq_ignore_ascii_case(name))
+}
+
+// Runs `generator` with an RNG seeded from `seed` and records the seed on the
+// grid, so the exact same maze can be regenerated later.
+pub fn generate_seeded(generator: &dyn MazeGenerator, grid: &mut Grid, seed: u64) {
+    grid.seed = Some(seed);
+    generator.generate(grid, &mut rng::seeded(seed));
+}
@@ -0,0 +1,79 @@
+use rand::{Rng, RngCore};
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::algorithms::growing_tree::{growing_tree, CellSelection};
+use crate::algorithms::MazeGenerator;
+use crate::grid::*;
+
+pub struct SimplifiedPrims;
+
+impl MazeGenerator for SimplifiedPrims {
+    fn name(&self) -> &'static str {
+        "simplified_prims"
+    }
+
+    fn generate(&self, grid: &mut Grid, rng: &mut dyn RngCore) {
+        simplified_prims(grid, rng);
+    }
+}
+
+pub struct TruePrims;
+
+impl MazeGenerator for TruePrims {
+    fn name(&self) -> &'static str {
+        "true_prims"
+    }
+
+    fn generate(&self, grid: &mut Grid, rng: &mut dyn RngCore) {
+        true_prims(grid, rng);
+    }
+}
+
+// Grows from a random active cell into a random unvisited neighbor.
+pub fn simplified_prims<R: Rng + ?Sized>(grid: &mut Grid, rng: &mut R) {
+    growing_tree(grid, CellSelection::Random, rng);
+}
+
+// Prim's with a random cost in 0..100 assigned to every cell.
+pub fn true_prims<R: Rng + ?Sized>(grid: &mut Grid, rng: &mut R) {
+    let costs = (0..grid.size()).map(|_| rng.gen_range(0..100u32)).collect::<Vec<_>>();
+    true_prims_with_costs(grid, &costs, rng);
+}
+
+// Always extends the cheapest active cell into its cheapest unvisited neighbor.
+// `costs` is indexed like `Grid::grid`; ties go to the lower index.
+pub fn true_prims_with_costs<R: Rng + ?Sized>(grid: &mut Grid, costs: &[u32], rng: &mut R) {
+    assert_eq!(costs.len(), grid.size(), "need one cost per cell");
+    if grid.size() == 0 {
+        return;
+    }
+
+    let mut visited = vec![false; grid.size()];
+    let start = grid.get_index(&grid.random_cell(rng)).unwrap();
+    visited[start] = true;
+    let mut active = BinaryHeap::new();
+    active.push(Reverse((costs[start], start)));
+
+    while let Some(&Reverse((_, idx))) = active.peek() {
+        let cell_pos = grid.grid[idx].coordinates.clone();
+        let next = grid.grid[idx]
+            .neighbors()
+            .into_iter()
+            .map(|n| grid.get_index(&n).unwrap())
+            .filter(|&n| !visited[n])
+            .min_by_key(|&n| (costs[n], n));
+
+        match next {
+            Some(next) => {
+                visited[next] = true;
+                grid.link(cell_pos, grid.grid[next].coordinates.clone());
+                active.push(Reverse((costs[next], next)));
+            }
+            None => {
+                active.pop();
+            }
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use rand::{Rng, RngCore};
+
+use crate::algorithms::MazeGenerator;
+use crate::grid::*;
+use crate::rng::choose;
+
+pub struct RecursiveBacktracker;
+
+impl MazeGenerator for RecursiveBacktracker {
+    fn name(&self) -> &'static str {
+        "recursive_backtracker"
+    }
+
+    fn generate(&self, grid: &mut Grid, rng: &mut dyn RngCore) {
+        recursive_backtracker(grid, rng);
+    }
+}
+
+// Depth-first carving. The "recursion" lives in a heap-allocated stack so the
+// depth is bounded by memory rather than the thread's stack size, which matters
+// on very large grids where the path can be millions of cells long.
+pub fn recursive_backtracker<R: Rng + ?Sized>(grid: &mut Grid, rng: &mut R) {
+    if grid.size() == 0 {
+        return;
+    }
+
+    let mut visited = vec![false; grid.size()];
+    let start = grid.random_cell(rng);
+    visited[grid.get_index(&start).unwrap()] = true;
+    let mut stack = vec![start];
+
+    while let Some(cell_pos) = stack.last() {
+        let unvisited = grid.get(cell_pos)
+            .unwrap()
+            .neighbors()
+            .into_iter()
+            .filter(|n| !visited[grid.get_index(n).unwrap()])
+            .collect::<Vec<_>>();
+
+        match choose(rng, &unvisited) {
+            Some(next) => {
+                let next = next.clone();
+                visited[grid.get_index(&next).unwrap()] = true;
+                grid.link(cell_pos.clone(), next.clone());
+                stack.push(next);
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+}
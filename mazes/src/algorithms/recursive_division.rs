@@ -0,0 +1,100 @@
+use rand::{Rng, RngCore};
+
+use crate::algorithms::MazeGenerator;
+use crate::grid::*;
+use crate::rng::random_index;
+
+// Regions no larger than `max_room_size` in both directions are left open as a
+// room with probability `room_probability`. The default never leaves rooms.
+pub struct RecursiveDivision {
+    pub max_room_size: isize,
+    pub room_probability: f64,
+}
+
+impl Default for RecursiveDivision {
+    fn default() -> RecursiveDivision {
+        RecursiveDivision { max_room_size: 0, room_probability: 0.0 }
+    }
+}
+
+impl MazeGenerator for RecursiveDivision {
+    fn name(&self) -> &'static str {
+        "recursive_division"
+    }
+
+    fn generate(&self, grid: &mut Grid, rng: &mut dyn RngCore) {
+        recursive_division(grid, self.max_room_size, self.room_probability, rng);
+    }
+}
+
+struct Region {
+    row: isize,
+    col: isize,
+    height: isize,
+    width: isize,
+}
+
+// Starts from a fully linked grid and repeatedly splits regions in two with a
+// wall that has a single gap. Regions are kept on an explicit stack.
+pub fn recursive_division<R: Rng + ?Sized>(grid: &mut Grid, max_room_size: isize, room_probability: f64, rng: &mut R) {
+    grid.link_all();
+
+    let mut regions = vec![Region { row: 0, col: 0, height: grid.rows, width: grid.cols }];
+    while let Some(region) = regions.pop() {
+        if region.height <= 1 || region.width <= 1 {
+            continue;
+        }
+
+        if region.height <= max_room_size && region.width <= max_room_size && rng.gen_bool(room_probability) {
+            continue;
+        }
+
+        let horizontal = if region.height != region.width {
+            region.height > region.width
+        } else {
+            rng.gen::<bool>()
+        };
+
+        if horizontal {
+            regions.extend(divide_horizontally(grid, region, rng));
+        } else {
+            regions.extend(divide_vertically(grid, region, rng));
+        }
+    }
+}
+
+fn divide_horizontally<R: Rng + ?Sized>(grid: &mut Grid, region: Region, rng: &mut R) -> [Region; 2] {
+    let south_of = random_index(rng, (region.height - 1) as usize) as isize;
+    let passage_at = random_index(rng, region.width as usize) as isize;
+
+    for x in 0..region.width {
+        if x != passage_at {
+            let cell_pos = Coordinates::new(region.row + south_of, region.col + x);
+            let south_pos = Coordinates::new(cell_pos.row + 1, cell_pos.col);
+            grid.unlink(cell_pos, south_pos);
+        }
+    }
+
+    [
+        Region { row: region.row, col: region.col, height: south_of + 1, width: region.width },
+        Region { row: region.row + south_of + 1, col: region.col, height: region.height - south_of - 1, width: region.width },
+    ]
+}
+
+fn divide_vertically<R: Rng + ?Sized>(grid: &mut Grid, region: Region, rng: &mut R) -> [Region; 2] {
+    let east_of = random_index(rng, (region.width - 1) as usize) as isize;
+    let passage_at = random_index(rng, region.height as usize) as isize;
+
+    for y in 0..region.height {
+        if y != passage_at {
+            let cell_pos = Coordinates::new(region.row + y, region.col + east_of);
+            let east_pos = Coordinates::new(cell_pos.row, cell_pos.col + 1);
+            grid.unlink(cell_pos, east_pos);
+        }
+    }
+
+    [
+        Region { row: region.row, col: region.col, height: region.height, width: east_of + 1 },
+        Region { row: region.row, col: region.col + east_of + 1, height: region.height, width: region.width - east_of - 1 },
+    ]
+}
@@ -0,0 +1,84 @@
+use rand::{Rng, RngCore};
+
+use crate::algorithms::MazeGenerator;
+use crate::grid::*;
+use crate::rng::random_index;
+
+// Carves runs along `run` and closes each one with a single passage towards
+// `close`, which must be perpendicular to it. A run keeps going with
+// probability `continue_probability`, and never gets longer than
+// `max_run_length` cells if one is set. The line on the `close` edge of the
+// grid can't be closed and becomes one unbroken corridor.
+pub struct Sidewinder {
+    pub run: Direction,
+    pub close: Direction,
+    pub continue_probability: f64,
+    pub max_run_length: Option<usize>,
+}
+
+impl Default for Sidewinder {
+    fn default() -> Sidewinder {
+        Sidewinder {
+            run: Direction::East,
+            close: Direction::South,
+            continue_probability: 0.5,
+            max_run_length: None,
+        }
+    }
+}
+
+impl MazeGenerator for Sidewinder {
+    fn name(&self) -> &'static str {
+        "sidewinder"
+    }
+
+    fn generate(&self, grid: &mut Grid, rng: &mut dyn RngCore) {
+        sidewinder_with(grid, self, rng);
+    }
+}
+
+pub fn sidewinder<R: Rng + ?Sized>(grid: &mut Grid, rng: &mut R) {
+    sidewinder_with(grid, &Sidewinder::default(), rng);
+}
+
+pub fn sidewinder_with<R: Rng + ?Sized>(grid: &mut Grid, options: &Sidewinder, rng: &mut R) {
+    assert!(options.run.is_vertical() != options.close.is_vertical(),
+            "sidewinder runs must be closed in a perpendicular direction");
+    assert!(options.max_run_length != Some(0), "max_run_length must be at least 1");
+
+    // Each line starts at the cell with no neighbor behind it and follows `run`.
+    let line_starts = grid.grid
+        .iter()
+        .filter(|cell| cell.neighbor(options.run.opposite()).is_none())
+        .map(|cell| cell.coordinates.clone())
+        .collect::<Vec<_>>();
+
+    for start in line_starts {
+        let mut run: Vec<Coordinates> = Vec::new();
+        let mut next_pos = Some(start);
+
+        while let Some(cell_pos) = next_pos {
+            let cell = grid.get(&cell_pos).unwrap();
+            next_pos = cell.neighbor(options.run).clone();
+            let can_close = cell.neighbor(options.close).is_some();
+            run.push(cell_pos.clone());
+
+            let at_max_length = options.max_run_length.is_some_and(|max| run.len() >= max);
+            let keep_running = next_pos.is_some()
+                && (!can_close || (!at_max_length && rng.gen_bool(options.continue_probability)));
+
+            if keep_running {
+                grid.link(cell_pos, next_pos.clone().unwrap());
+                continue;
+            }
+
+            if !can_close { continue; }
+
+            // Close the run
+            let from_pos = run[random_index(rng, run.len())].clone();
+            let to_pos = grid.get(&from_pos).unwrap().neighbor(options.close).clone().unwrap();
+            grid.link(from_pos, to_pos);
+            run.clear();
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use rand::{Rng, RngCore};
+
+use crate::algorithms::MazeGenerator;
+use crate::grid::*;
+use crate::rng::{choose, random_index};
+
+pub struct Wilsons;
+
+impl MazeGenerator for Wilsons {
+    fn name(&self) -> &'static str {
+        "wilsons"
+    }
+
+    fn generate(&self, grid: &mut Grid, rng: &mut dyn RngCore) {
+        wilsons(grid, rng);
+    }
+}
+
+// Loop-erased random walks from unvisited cells until they hit the maze. Like
+// Aldous-Broder this gives uniform spanning trees, but it is slow at the start
+// rather than the end.
+pub fn wilsons<R: Rng + ?Sized>(grid: &mut Grid, rng: &mut R) {
+    if grid.size() == 0 {
+        return;
+    }
+
+    let mut in_maze = vec![false; grid.size()];
+    let mut unvisited: Vec<Coordinates> = grid.grid.iter().map(|c| c.coordinates.clone()).collect();
+
+    let first = unvisited.swap_remove(random_index(rng, unvisited.len()));
+    in_maze[grid.get_index(&first).unwrap()] = true;
+
+    let mut walk = LoopErasedPath::new(grid);
+
+    while !unvisited.is_empty() {
+        let start_idx = random_index(rng, unvisited.len());
+        if in_maze[grid.get_index(&unvisited[start_idx]).unwrap()] {
+            unvisited.swap_remove(start_idx);
+            continue;
+        }
+
+        let mut cell_pos = unvisited[start_idx].clone();
+        walk.push(grid, &cell_pos);
+
+        while !in_maze[grid.get_index(&cell_pos).unwrap()] {
+            let neighbors = grid.get(&cell_pos).unwrap().neighbors();
+            cell_pos = choose(rng, &neighbors).unwrap().clone();
+            walk.push(grid, &cell_pos);
+        }
+
+        let path = walk.take(grid);
+        for pair in path.windows(2) {
+            grid.link(pair[0].clone(), pair[1].clone());
+        }
+        for pos in &path {
+            in_maze[grid.get_index(pos).unwrap()] = true;
+        }
+    }
+}
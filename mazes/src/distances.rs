@@ -0,0 +1,149 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+use crate::grid::*;
+
+// Distance from `root` to every cell of a grid, either in steps through linked
+// passages or (for `weighted`) as the sum of `Cell::weight` of every cell
+// entered. Cells that can't be reached from the root have no distance. Both
+// constructors return `None` when the root isn't a cell of the grid.
+#[derive(Debug, Clone)]
+pub struct Distances {
+    root: Coordinates,
+    rows: isize,
+    cols: isize,
+    // Indexed like `Grid::grid`.
+    distances: Vec<Option<usize>>,
+    // Index of the cell each reached cell was entered from on its best route.
+    came_from: Vec<Option<usize>>,
+}
+
+impl Distances {
+    // Breadth-first flood from `root` along `Cell::links`. Links are followed
+    // in `Cell::neighbors` order (not `HashSet` order) so `path_to` picks the
+    // same route every run when there is more than one.
+    pub fn new(grid: &Grid, root: &Coordinates) -> Option<Distances> {
+        let mut distances = vec![None; grid.size()];
+        let mut came_from = vec![None; grid.size()];
+        let mut frontier = VecDeque::new();
+
+        let root_idx = grid.get_index(root)?;
+        distances[root_idx] = Some(0);
+        frontier.push_back(root_idx);
+
+        while let Some(idx) = frontier.pop_front() {
+            let distance = distances[idx].unwrap();
+            let cell = &grid.grid[idx];
+            for link in cell.neighbors().iter().filter(|n| cell.links.contains(n)) {
+                let link_idx = grid.get_index(link).unwrap();
+                if distances[link_idx].is_none() {
+                    distances[link_idx] = Some(distance + 1);
+                    came_from[link_idx] = Some(idx);
+                    frontier.push_back(link_idx);
+                }
+            }
+        }
+
+        Some(Distances { root: root.clone(), rows: grid.rows, cols: grid.cols, distances, came_from })
+    }
+
+    // Dijkstra's algorithm with a priority queue. Stepping into a cell costs
+    // its weight, so the cheapest route may be longer than the shortest one.
+    pub fn weighted(grid: &Grid, root: &Coordinates) -> Option<Distances> {
+        let mut distances = vec![None; grid.size()];
+        let mut came_from = vec![None; grid.size()];
+        let mut frontier = BinaryHeap::new();
+
+        let root_idx = grid.get_index(root)?;
+        distances[root_idx] = Some(0);
+        frontier.push(Reverse((0, root_idx)));
+
+        while let Some(Reverse((distance, idx))) = frontier.pop() {
+            if distances[idx].is_some_and(|best| best < distance) {
+                continue;
+            }
+
+            let cell = &grid.grid[idx];
+            for link in cell.neighbors().iter().filter(|n| cell.links.contains(n)) {
+                let link_idx = grid.get_index(link).unwrap();
+                let cost = distance + grid.grid[link_idx].weight;
+                if distances[link_idx].is_none_or(|best| cost < best) {
+                    distances[link_idx] = Some(cost);
+                    came_from[link_idx] = Some(idx);
+                    frontier.push(Reverse((cost, link_idx)));
+                }
+            }
+        }
+
+        Some(Distances { root: root.clone(), rows: grid.rows, cols: grid.cols, distances, came_from })
+    }
+
+    pub fn root(&self) -> &Coordinates {
+        &self.root
+    }
+
+    fn index(&self, pos: &Coordinates) -> Option<usize> {
+        if pos.row >= 0 && pos.row < self.rows && pos.col >= 0 && pos.col < self.cols {
+            Some((pos.row * self.cols + pos.col) as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, pos: &Coordinates) -> Option<usize> {
+        self.index(pos).and_then(|idx| self.distances[idx])
+    }
+
+    // The farthest reachable cell and its distance. Ties go to the first cell
+    // in row-major order.
+    pub fn max(&self) -> (Coordinates, usize) {
+        let mut best = (self.root.clone(), 0);
+        for (idx, distance) in self.distances.iter().enumerate() {
+            if let Some(distance) = *distance {
+                if distance > best.1 {
+                    best = (Coordinates::new(idx as isize / self.cols, idx as isize % self.cols), distance);
+                }
+            }
+        }
+        best
+    }
+
+    // Shortest (or cheapest, for weighted distances) path from the root to
+    // `goal`, both included. Follows the recorded predecessors back from the
+    // goal, so cells with a weight of 0 (which tie with their neighbor) can't
+    // send it round in circles.
+    pub fn path_to(&self, grid: &Grid, goal: &Coordinates) -> Option<Vec<Coordinates>> {
+        let mut current = self.index(goal)?;
+        self.distances[current]?;
+        let mut path = vec![goal.clone()];
+        while let Some(previous) = self.came_from[current] {
+            path.push(grid.grid[previous].coordinates.clone());
+            current = previous;
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LongestPath {
+    pub start: Coordinates,
+    pub goal: Coordinates,
+    // From `start` to `goal`, both included.
+    pub path: Vec<Coordinates>,
+}
+
+// In a perfect maze the cell farthest from any cell is one end of the longest
+// path, and the cell farthest from that is the other end. For mazes with loops
+// this is still a good heuristic but not guaranteed to be the longest.
+pub fn longest_path(grid: &Grid) -> Option<LongestPath> {
+    let first = grid.grid.first()?.coordinates.clone();
+    let (start, _) = Distances::new(grid, &first)?.max();
+
+    let from_start = Distances::new(grid, &start)?;
+    let (goal, _) = from_start.max();
+    let path = from_start.path_to(grid, &goal)?;
+
+    Some(LongestPath { start, goal, path })
+}
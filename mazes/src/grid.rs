@@ -0,0 +1,333 @@
+use rand::Rng;
+
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::rng::{choose, random_index, shuffle};
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone)]
+pub struct Coordinates {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Coordinates {
+    pub fn new(row: isize, col: isize) -> Coordinates {
+        Coordinates{row, col}
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    // Position in `Direction::ALL`, for per-direction arrays.
+    pub fn index(self) -> usize {
+        match self {
+            Direction::North => 0,
+            Direction::East => 1,
+            Direction::South => 2,
+            Direction::West => 3,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.turn_right().opposite()
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::South => Direction::North,
+            Direction::East => Direction::West,
+            Direction::West => Direction::East,
+        }
+    }
+
+    pub fn is_vertical(self) -> bool {
+        self == Direction::North || self == Direction::South
+    }
+}
+
+#[derive(Debug)]
+pub struct Cell {
+    pub coordinates: Coordinates,
+    pub links: HashSet<Coordinates>,
+    pub north: Option<Coordinates>,
+    pub south: Option<Coordinates>,
+    pub east: Option<Coordinates>,
+    pub west: Option<Coordinates>,
+    // Cost of stepping into this cell for weighted path finding (1 by default).
+    pub weight: usize,
+}
+
+fn validate_coordinates(pos: Coordinates, rows: isize, cols: isize) -> Option<Coordinates> {
+    if pos.row >= 0 && pos.row < rows && pos.col >=0 && pos.col < cols {
+        Some(pos)
+    } else {
+        None
+    }
+}
+
+impl Cell {
+    pub fn new(row: isize, col: isize, rows: isize, cols: isize) -> Cell {
+        let coordinates = Coordinates{row, col};
+        Cell {
+            coordinates,
+            links: HashSet::new(),
+            north: validate_coordinates(Coordinates{row: row-1, col}, rows, cols),
+            south: validate_coordinates(Coordinates{row: row+1, col}, rows, cols),
+            east:  validate_coordinates(Coordinates{row , col: col+1}, rows, cols),
+            west:  validate_coordinates(Coordinates{row , col: col-1}, rows, cols),
+            weight: 1}
+    }
+
+    pub fn is_linked(&self, other_coordinates: &Option<Coordinates>) -> bool {
+        match other_coordinates {
+            Some(coordinates) => self.links.contains(coordinates),
+            None => false
+        }
+    }
+
+    pub fn unlink(&mut self, cell: &mut Cell) {
+        self.links.remove(&cell.coordinates);
+        cell.links.remove(&self.coordinates);
+    }
+
+    pub fn neighbor(&self, direction: Direction) -> &Option<Coordinates> {
+        match direction {
+            Direction::North => &self.north,
+            Direction::South => &self.south,
+            Direction::East => &self.east,
+            Direction::West => &self.west,
+        }
+    }
+
+    pub fn neighbors(&self) -> Vec<Coordinates> {
+        vec![self.north.clone(),
+        self.south.clone(),
+        self.east.clone(),
+        self.west.clone()]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
+// The passages leaving one row of a rectangular maze, for algorithms and
+// renderers that work a row at a time instead of on a whole `Grid`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowLinks {
+    pub row: isize,
+    // `east[col]` is true when (row, col) is linked to (row, col + 1).
+    pub east: Vec<bool>,
+    // `south[col]` is true when (row, col) is linked to (row + 1, col).
+    pub south: Vec<bool>,
+}
+
+#[derive(Debug)]
+pub struct Grid {
+    pub grid: Vec<Cell>,
+    pub rows: isize,
+    pub cols: isize,
+    // The seed the maze was generated from, if it came from `generate_seeded`.
+    pub seed: Option<u64>,
+}
+
+impl Grid {
+    pub fn new(rows: isize, cols: isize) -> Grid {
+        let num_cells = (rows * cols) as usize;
+        let mut grid = Vec::with_capacity(num_cells);
+        for row in 0..rows {
+            for col in 0..cols {
+                grid.push(Cell::new(row, col, rows, cols));
+            }
+        }
+        Grid {grid,  rows, cols, seed: None}
+    }
+
+    pub fn link(&mut self, pos1: Coordinates, pos2: Coordinates) {
+        let cell1 = self.get_index(&pos1).unwrap();
+        let cell2 = self.get_index(&pos2).unwrap();
+        self.grid[cell1].links.insert(pos2.clone());
+        self.grid[cell2].links.insert(pos1.clone());
+    }
+
+    pub fn unlink(&mut self, pos1: Coordinates, pos2: Coordinates) {
+        let idx1 = self.get_index(&pos1).unwrap();
+        let idx2 = self.get_index(&pos2).unwrap();
+        if idx1 == idx2 {
+            return;
+        }
+        let (low, high) = (idx1.min(idx2), idx1.max(idx2));
+        let (head, tail) = self.grid.split_at_mut(high);
+        head[low].unlink(&mut tail[0]);
+    }
+
+    // Links every cell to all of its neighbors, the starting point for
+    // wall-adding algorithms such as recursive division.
+    pub fn link_all(&mut self) {
+        for cell in self.grid.iter_mut() {
+            cell.links = cell.neighbors().into_iter().collect();
+        }
+    }
+
+    pub fn dead_ends(&self) -> Vec<Coordinates> {
+        self.grid.iter()
+            .filter(|cell| cell.links.len() == 1)
+            .map(|cell| cell.coordinates.clone())
+            .collect()
+    }
+
+    // Removes each dead end with probability `p` by linking it to one of its
+    // unlinked neighbors, preferring neighbors that are dead ends themselves.
+    // This adds loops, so there is more than one route between cells.
+    pub fn braid<R: Rng + ?Sized>(&mut self, p: f64, rng: &mut R) {
+        let mut dead_ends = self.dead_ends();
+        shuffle(rng, &mut dead_ends);
+
+        for pos in dead_ends {
+            let cell = self.get(&pos).unwrap();
+            if cell.links.len() != 1 || !rng.gen_bool(p) {
+                continue;
+            }
+
+            let unlinked = cell.neighbors()
+                .into_iter()
+                .filter(|n| !cell.links.contains(n))
+                .collect::<Vec<_>>();
+            let best = unlinked.iter()
+                .filter(|n| self.get(n).unwrap().links.len() == 1)
+                .cloned()
+                .collect::<Vec<_>>();
+            let candidates = if best.is_empty() { &unlinked } else { &best };
+
+            if let Some(neighbor) = choose(rng, candidates) {
+                let neighbor = neighbor.clone();
+                self.link(pos, neighbor);
+            }
+        }
+    }
+
+    pub fn get_index(&self, pos: &Coordinates) -> Option<usize> {
+        if pos.row >= 0 && pos.row < self.rows && pos.col >=0 && pos.col < self.cols {
+            Some((pos.row * self.cols + pos.col) as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, pos: &Coordinates) -> Option<&Cell> {
+        self.get_index(pos).map(|i| &self.grid[i])
+    }
+
+    pub fn size(&self) -> usize {
+        self.grid.len()
+    }
+
+    pub fn random_cell<R: Rng + ?Sized>(&self, rng: &mut R) -> Coordinates {
+        self.grid[random_index(rng, self.size())].coordinates.clone()
+    }
+
+    pub fn row_links(&self, row: isize) -> RowLinks {
+        let cells = (0..self.cols).map(|col| self.get(&Coordinates::new(row, col)).unwrap());
+        let (east, south) = cells.map(|c| (c.is_linked(&c.east), c.is_linked(&c.south))).unzip();
+        RowLinks { row, east, south }
+    }
+
+    pub fn get_mut(&mut self, pos: Coordinates) -> Option<&mut Cell> {
+        if pos.row >= 0 && pos.row < self.rows && pos.col >=0 && pos.col < self.cols {
+            Some(&mut self.grid[(pos.row * self.cols + pos.col) as usize])
+        } else {
+            None
+        }
+    }
+}
+
+// Records a walk through the maze while erasing any loop it closes, leaving the
+// route from the first cell to the last without detours.
+pub(crate) struct LoopErasedPath {
+    path: Vec<Coordinates>,
+    // Position of each cell in `path`, indexed like `Grid::grid`.
+    position: Vec<Option<usize>>,
+}
+
+impl LoopErasedPath {
+    pub(crate) fn new(grid: &Grid) -> LoopErasedPath {
+        LoopErasedPath { path: Vec::new(), position: vec![None; grid.size()] }
+    }
+
+    pub(crate) fn push(&mut self, grid: &Grid, pos: &Coordinates) {
+        let idx = grid.get_index(pos).unwrap();
+        match self.position[idx] {
+            Some(position) => {
+                for erased in self.path.drain(position + 1..) {
+                    self.position[grid.get_index(&erased).unwrap()] = None;
+                }
+            }
+            None => {
+                self.position[idx] = Some(self.path.len());
+                self.path.push(pos.clone());
+            }
+        }
+    }
+
+    pub(crate) fn into_path(self) -> Vec<Coordinates> {
+        self.path
+    }
+
+    // Hands over the walk so far and starts a new one, without reallocating
+    // the position table.
+    pub(crate) fn take(&mut self, grid: &Grid) -> Vec<Coordinates> {
+        for pos in &self.path {
+            self.position[grid.get_index(pos).unwrap()] = None;
+        }
+        std::mem::take(&mut self.path)
+    }
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let line = format!("+{}", "---+".repeat(self.cols as usize));
+        writeln!(f, "{}", line)?;
+
+        for row in 0..self.rows {
+            let mut line1 = "|".to_string();
+            let mut line2 = "+".to_string();
+            for col in 0..self.cols {
+                let cell = self.get(&Coordinates::new(row,col)).unwrap();
+                if cell.is_linked(&cell.east) {
+                    line1.push_str("    ");
+                } else {
+                    line1.push_str("   |")
+                }
+
+                if cell.is_linked(&cell.south) {
+                    line2.push_str("   +")
+                } else {
+                    line2.push_str("---+")
+                }
+            }
+            writeln!(f, "{}", line1)?;
+            writeln!(f, "{}", line2)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,6 @@
+pub mod algorithms;
+pub mod distances;
+pub mod grid;
+pub mod render;
+pub mod rng;
+pub mod solvers;
@@ -0,0 +1,26 @@
+use crate::grid::*;
+
+pub mod png;
+pub mod svg;
+pub mod text;
+pub mod tiles;
+
+// Whether there is a wall on horizontal grid line `line` (0 is the top border,
+// `rows` the bottom) above column `col`.
+pub(crate) fn has_horizontal_wall(grid: &Grid, line: isize, col: isize) -> bool {
+    if line == 0 || line == grid.rows {
+        return true;
+    }
+    let cell = grid.get(&Coordinates::new(line - 1, col)).unwrap();
+    !cell.is_linked(&cell.south)
+}
+
+// Whether there is a wall on vertical grid line `line` (0 is the left border,
+// `cols` the right) beside row `row`.
+pub(crate) fn has_vertical_wall(grid: &Grid, line: isize, row: isize) -> bool {
+    if line == 0 || line == grid.cols {
+        return true;
+    }
+    let cell = grid.get(&Coordinates::new(row, line - 1)).unwrap();
+    !cell.is_linked(&cell.east)
+}
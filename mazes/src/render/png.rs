@@ -0,0 +1,234 @@
+use image::imageops::{self, FilterType};
+use image::error::{ImageError, ParameterError, ParameterErrorKind};
+use image::{ImageResult, RgbImage, Rgb};
+use imageproc::drawing::*;
+use imageproc::rect::Rect;
+
+use std::path::Path;
+
+use crate::distances::Distances;
+use crate::grid::*;
+
+// House style for raster output. Sizes are in pixels; `margin` is extra
+// background around the maze.
+#[derive(Debug, Clone)]
+pub struct RenderOptions {
+    pub cell_size: u32,
+    pub wall_thickness: u32,
+    pub margin: u32,
+    pub foreground: Rgb<u8>,
+    pub background: Rgb<u8>,
+    // Render at `SUPERSAMPLING` times the size and scale down, smoothing the
+    // edges of the route and markers.
+    pub antialias: bool,
+}
+
+impl Default for RenderOptions {
+    fn default() -> RenderOptions {
+        RenderOptions {
+            cell_size: 30,
+            wall_thickness: 1,
+            margin: 0,
+            foreground: Rgb([255u8, 255u8, 255u8]),
+            background: Rgb([0u8, 0u8, 0u8]),
+            antialias: false,
+        }
+    }
+}
+
+const SUPERSAMPLING: u32 = 4;
+
+// Where each part of the grid lands in the image, in (x, y) pixel space. Grid
+// lines are `wall` pixels thick and `cell` pixels apart, so along each axis the
+// layout is: margin, wall, cell, wall, cell, ..., wall, margin.
+struct PixelMap {
+    cell: u32,
+    wall: u32,
+    margin: u32,
+    rows: u32,
+    cols: u32,
+}
+
+impl PixelMap {
+    fn new(grid: &Grid, options: &RenderOptions) -> PixelMap {
+        PixelMap {
+            cell: options.cell_size,
+            wall: options.wall_thickness,
+            margin: options.margin,
+            rows: grid.rows as u32,
+            cols: grid.cols as u32,
+        }
+    }
+
+    fn image_size(&self) -> (u32, u32) {
+        let stride = self.cell + self.wall;
+        (2 * self.margin + self.cols * stride + self.wall, 2 * self.margin + self.rows * stride + self.wall)
+    }
+
+    // Left edge of the vertical grid line before column `col`.
+    fn line_x(&self, col: isize) -> i32 {
+        (self.margin + col as u32 * (self.cell + self.wall)) as i32
+    }
+
+    // Top edge of the horizontal grid line above row `row`.
+    fn line_y(&self, row: isize) -> i32 {
+        (self.margin + row as u32 * (self.cell + self.wall)) as i32
+    }
+
+    // The cell together with the four grid lines around it.
+    fn cell_with_walls(&self, pos: &Coordinates) -> Rect {
+        Rect::at(self.line_x(pos.col), self.line_y(pos.row)).of_size(self.cell + 2 * self.wall, self.cell + 2 * self.wall)
+    }
+
+    fn north_wall(&self, pos: &Coordinates) -> Rect {
+        Rect::at(self.line_x(pos.col), self.line_y(pos.row)).of_size(self.cell + 2 * self.wall, self.wall)
+    }
+
+    fn south_wall(&self, pos: &Coordinates) -> Rect {
+        Rect::at(self.line_x(pos.col), self.line_y(pos.row + 1)).of_size(self.cell + 2 * self.wall, self.wall)
+    }
+
+    fn west_wall(&self, pos: &Coordinates) -> Rect {
+        Rect::at(self.line_x(pos.col), self.line_y(pos.row)).of_size(self.wall, self.cell + 2 * self.wall)
+    }
+
+    fn east_wall(&self, pos: &Coordinates) -> Rect {
+        Rect::at(self.line_x(pos.col + 1), self.line_y(pos.row)).of_size(self.wall, self.cell + 2 * self.wall)
+    }
+
+    fn center(&self, pos: &Coordinates) -> (i32, i32) {
+        let offset = (self.wall + self.cell / 2) as i32;
+        (self.line_x(pos.col) + offset, self.line_y(pos.row) + offset)
+    }
+
+    // A `thickness` wide band between two cell centers in the same row or column.
+    fn segment(&self, from: &Coordinates, to: &Coordinates, thickness: u32) -> Rect {
+        let (x1, y1) = self.center(from);
+        let (x2, y2) = self.center(to);
+        let half = (thickness / 2) as i32;
+        Rect::at(x1.min(x2) - half, y1.min(y2) - half)
+            .of_size((x1 - x2).unsigned_abs() + thickness, (y1 - y2).unsigned_abs() + thickness)
+    }
+}
+
+pub(crate) fn parameter_error(message: String) -> ImageError {
+    ImageError::Parameter(ParameterError::from_kind(ParameterErrorKind::Generic(message)))
+}
+
+// A route is drawn as straight bands between consecutive cells, so it has to
+// stay on the grid and move one cell north, south, east or west at a time.
+fn check_route(grid: &Grid, route: &[Coordinates]) -> ImageResult<()> {
+    if let Some(pos) = route.iter().find(|pos| grid.get(pos).is_none()) {
+        return Err(parameter_error(format!("route cell {:?} is outside the grid", pos)));
+    }
+    for pair in route.windows(2) {
+        if !grid.get(&pair[0]).unwrap().neighbors().contains(&pair[1]) {
+            return Err(parameter_error(format!("route steps from {:?} to {:?}, which aren't adjacent", pair[0], pair[1])));
+        }
+    }
+    Ok(())
+}
+
+// With `distances`, every cell is filled with a color that gets darker the
+// farther it is from the root, as a heat map.
+pub fn draw(grid: &Grid, path: &Path, options: &RenderOptions, route: Option<&[Coordinates]>, distances: Option<&Distances>) -> ImageResult<()> {
+    render(grid, options, route, distances)?.save(path)
+}
+
+// Zero-sized cells or walls would collapse the maze into nothing, and `Rect`
+// can't be empty, so both are errors.
+pub fn render(grid: &Grid, options: &RenderOptions, route: Option<&[Coordinates]>, distances: Option<&Distances>) -> ImageResult<RgbImage> {
+    if options.cell_size == 0 || options.wall_thickness == 0 {
+        return Err(parameter_error(format!("cell_size ({}) and wall_thickness ({}) must be positive",
+                                           options.cell_size, options.wall_thickness)));
+    }
+    if let Some(route) = route {
+        check_route(grid, route)?;
+    }
+    if !options.antialias {
+        return Ok(render_aliased(grid, options, route, distances));
+    }
+
+    let (width, height) = PixelMap::new(grid, options).image_size();
+    let scaled = RenderOptions {
+        cell_size: options.cell_size * SUPERSAMPLING,
+        wall_thickness: options.wall_thickness * SUPERSAMPLING,
+        margin: options.margin * SUPERSAMPLING,
+        antialias: false,
+        ..options.clone()
+    };
+    let img = render_aliased(grid, &scaled, route, distances);
+    Ok(imageops::resize(&img, width, height, FilterType::Triangle))
+}
+
+fn render_aliased(grid: &Grid, options: &RenderOptions, route: Option<&[Coordinates]>, distances: Option<&Distances>) -> RgbImage {
+    let map = PixelMap::new(grid, options);
+    let (width, height) = map.image_size();
+    let mut img = RgbImage::from_pixel(width, height, options.background);
+
+    if let Some(distances) = distances {
+        fill_cells(&mut img, grid, distances, &map);
+    }
+
+    // Each cell draws the walls on its north and west sides only when they are
+    // also the outer border; otherwise the neighbor's south or east wall covers it.
+    for cell in &grid.grid {
+        let pos = &cell.coordinates;
+        if cell.north.is_none() {
+            draw_filled_rect_mut(&mut img, map.north_wall(pos), options.foreground);
+        }
+        if cell.west.is_none() {
+            draw_filled_rect_mut(&mut img, map.west_wall(pos), options.foreground);
+        }
+        if !cell.is_linked(&cell.south) {
+            draw_filled_rect_mut(&mut img, map.south_wall(pos), options.foreground);
+        }
+        if !cell.is_linked(&cell.east) {
+            draw_filled_rect_mut(&mut img, map.east_wall(pos), options.foreground);
+        }
+    }
+
+    if let Some(route) = route {
+        draw_route(&mut img, route, &map);
+    }
+
+    img
+}
+
+fn heat_color(distance: usize, max: usize) -> Rgb<u8> {
+    let near = [60f32, 220f32, 60f32];
+    let far = [0f32, 40f32, 0f32];
+    let t = if max == 0 { 0f32 } else { distance as f32 / max as f32 };
+    let channel = |i: usize| (near[i] + (far[i] - near[i]) * t).round() as u8;
+    Rgb([channel(0), channel(1), channel(2)])
+}
+
+// Cells the distances don't reach are left unfilled. The fill covers the
+// cell's grid lines too so open passages aren't crossed by a gap; walls are
+// drawn over it afterwards.
+fn fill_cells(img: &mut RgbImage, grid: &Grid, distances: &Distances, map: &PixelMap) {
+    let (_, max) = distances.max();
+    for cell in &grid.grid {
+        if let Some(distance) = distances.get(&cell.coordinates) {
+            draw_filled_rect_mut(img, map.cell_with_walls(&cell.coordinates), heat_color(distance, max));
+        }
+    }
+}
+
+// Draws `route` as a line through the cell centers, as thick as the walls, with
+// a green marker on the first cell and a red one on the last.
+fn draw_route(img: &mut RgbImage, route: &[Coordinates], map: &PixelMap) {
+    let path_color = Rgb([255u8, 160u8, 0u8]);
+    let start_color = Rgb([0u8, 200u8, 0u8]);
+    let goal_color = Rgb([220u8, 0u8, 0u8]);
+
+    for pair in route.windows(2) {
+        draw_filled_rect_mut(img, map.segment(&pair[0], &pair[1], map.wall), path_color);
+    }
+
+    let marker_radius = (map.cell / 4) as i32;
+    if let (Some(start), Some(goal)) = (route.first(), route.last()) {
+        draw_filled_circle_mut(img, map.center(start), marker_radius, start_color);
+        draw_filled_circle_mut(img, map.center(goal), marker_radius, goal_color);
+    }
+}
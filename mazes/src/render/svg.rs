@@ -0,0 +1,73 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::grid::*;
+use crate::render::{has_horizontal_wall, has_vertical_wall};
+
+// Sizes are in SVG user units. The margin is left around the outer walls.
+#[derive(Debug, Clone)]
+pub struct SvgOptions {
+    pub cell_size: f64,
+    pub stroke_width: f64,
+    pub margin: f64,
+}
+
+impl Default for SvgOptions {
+    fn default() -> SvgOptions {
+        SvgOptions { cell_size: 30.0, stroke_width: 2.0, margin: 10.0 }
+    }
+}
+
+// Start and end offsets (in cells) of every run of consecutive walls along a line.
+fn wall_runs<F: Fn(isize) -> bool>(len: isize, is_wall: F) -> Vec<(isize, isize)> {
+    let mut runs = Vec::new();
+    let mut run_start = None;
+    for i in 0..=len {
+        match (run_start, i < len && is_wall(i)) {
+            (None, true) => run_start = Some(i),
+            (Some(start), false) => {
+                runs.push((start, i));
+                run_start = None;
+            }
+            _ => {}
+        }
+    }
+    runs
+}
+
+// Walls are merged into as few segments as possible: each straight run of
+// walls is a single subpath of one `<path>` element.
+pub fn to_svg(grid: &Grid, options: &SvgOptions) -> String {
+    let size = options.cell_size;
+    let margin = options.margin;
+    let width = grid.cols as f64 * size + 2.0 * margin;
+    let height = grid.rows as f64 * size + 2.0 * margin;
+
+    let mut d = String::new();
+    for line in 0..=grid.rows {
+        let y = margin + line as f64 * size;
+        for (start, end) in wall_runs(grid.cols, |col| has_horizontal_wall(grid, line, col)) {
+            write!(d, "M{} {}H{}", margin + start as f64 * size, y, margin + end as f64 * size).unwrap();
+        }
+    }
+    for line in 0..=grid.cols {
+        let x = margin + line as f64 * size;
+        for (start, end) in wall_runs(grid.rows, |row| has_vertical_wall(grid, line, row)) {
+            write!(d, "M{} {}V{}", x, margin + start as f64 * size, margin + end as f64 * size).unwrap();
+        }
+    }
+
+    let mut svg = String::new();
+    writeln!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+             w = width, h = height).unwrap();
+    writeln!(svg, r#"  <path d="{}" fill="none" stroke="black" stroke-width="{}" stroke-linecap="square"/>"#,
+             d, options.stroke_width).unwrap();
+    writeln!(svg, "</svg>").unwrap();
+    svg
+}
+
+pub fn save_svg(grid: &Grid, path: &Path, options: &SvgOptions) -> io::Result<()> {
+    fs::write(path, to_svg(grid, options))
+}
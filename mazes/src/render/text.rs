@@ -0,0 +1,82 @@
+use std::io::{self, Write};
+
+use crate::grid::*;
+use crate::render::{has_horizontal_wall, has_vertical_wall};
+
+// Writes the same ASCII art as `Grid`'s `Display` impl, but consumes one row at
+// a time (e.g. from `ellers_rows`) so the whole maze never has to be in memory.
+pub fn write_rows<W, I>(out: &mut W, cols: isize, rows: I) -> io::Result<()>
+    where W: Write, I: IntoIterator<Item = RowLinks>
+{
+    writeln!(out, "+{}", "---+".repeat(cols as usize))?;
+
+    for row in rows {
+        let mut line1 = "|".to_string();
+        let mut line2 = "+".to_string();
+        for (&east, &south) in row.east.iter().zip(&row.south) {
+            line1.push_str(if east { "    " } else { "   |" });
+            line2.push_str(if south { "   +" } else { "---+" });
+        }
+        writeln!(out, "{}", line1)?;
+        writeln!(out, "{}", line2)?;
+    }
+
+    Ok(())
+}
+
+// Box-drawing glyph for a grid corner given which of the four wall segments
+// meeting there (up, down, left, right) are present.
+fn junction(up: bool, down: bool, left: bool, right: bool) -> char {
+    match (up, down, left, right) {
+        (false, false, false, false) => ' ',
+        (true, false, false, false) => '╵',
+        (false, true, false, false) => '╷',
+        (false, false, true, false) => '╴',
+        (false, false, false, true) => '╶',
+        (true, true, false, false) => '│',
+        (false, false, true, true) => '─',
+        (false, true, false, true) => '┌',
+        (false, true, true, false) => '┐',
+        (true, false, false, true) => '└',
+        (true, false, true, false) => '┘',
+        (true, true, false, true) => '├',
+        (true, true, true, false) => '┤',
+        (false, true, true, true) => '┬',
+        (true, false, true, true) => '┴',
+        (true, true, true, true) => '┼',
+    }
+}
+
+// Same layout as `Grid`'s ASCII `Display` impl, drawn with Unicode box-drawing
+// characters and the right junction at every corner.
+pub fn to_unicode(grid: &Grid) -> String {
+    let mut out = String::new();
+
+    for line in 0..=grid.rows {
+        // The corners and walls along horizontal grid line `line`.
+        for col in 0..=grid.cols {
+            let up = line > 0 && has_vertical_wall(grid, col, line - 1);
+            let down = line < grid.rows && has_vertical_wall(grid, col, line);
+            let left = col > 0 && has_horizontal_wall(grid, line, col - 1);
+            let right = col < grid.cols && has_horizontal_wall(grid, line, col);
+            out.push(junction(up, down, left, right));
+            if col < grid.cols {
+                out.push_str(if right { "───" } else { "   " });
+            }
+        }
+        out.push('\n');
+
+        // The cells of row `line` and the walls between them.
+        if line < grid.rows {
+            for col in 0..=grid.cols {
+                out.push(if has_vertical_wall(grid, col, line) { '│' } else { ' ' });
+                if col < grid.cols {
+                    out.push_str("   ");
+                }
+            }
+            out.push('\n');
+        }
+    }
+
+    out
+}
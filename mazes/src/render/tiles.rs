@@ -0,0 +1,60 @@
+use image::{ImageResult, RgbImage};
+use imageproc::drawing::draw_filled_rect_mut;
+use imageproc::rect::Rect;
+
+use std::path::Path;
+
+use crate::grid::*;
+use crate::render::png::{parameter_error, RenderOptions};
+
+// Block map of the maze with `true` for wall tiles and `false` for floor, as
+// `(2 * rows + 1)` rows of `(2 * cols + 1)` tiles. Cell (row, col) is the
+// floor tile at (2 * row + 1, 2 * col + 1), the tiles between cells are floor
+// when the cells are linked, and every grid corner is a wall.
+pub fn to_tiles(grid: &Grid) -> Vec<Vec<bool>> {
+    let mut tiles = vec![vec![true; (2 * grid.cols + 1) as usize]; (2 * grid.rows + 1) as usize];
+
+    for cell in &grid.grid {
+        let y = (2 * cell.coordinates.row + 1) as usize;
+        let x = (2 * cell.coordinates.col + 1) as usize;
+        tiles[y][x] = false;
+        if cell.is_linked(&cell.east) {
+            tiles[y][x + 1] = false;
+        }
+        if cell.is_linked(&cell.south) {
+            tiles[y + 1][x] = false;
+        }
+    }
+
+    tiles
+}
+
+// Each tile is a `cell_size` square, walls in the foreground color and floor
+// in the background color. `wall_thickness` and `antialias` don't apply.
+pub fn render_tiles(grid: &Grid, options: &RenderOptions) -> ImageResult<RgbImage> {
+    if options.cell_size == 0 {
+        return Err(parameter_error("cell_size must be positive".to_string()));
+    }
+
+    let tiles = to_tiles(grid);
+    let size = options.cell_size;
+    let width = tiles[0].len() as u32 * size + 2 * options.margin;
+    let height = tiles.len() as u32 * size + 2 * options.margin;
+    let mut img = RgbImage::from_pixel(width, height, options.background);
+
+    for (y, row) in tiles.iter().enumerate() {
+        for (x, &wall) in row.iter().enumerate() {
+            if wall {
+                let rect = Rect::at((options.margin + x as u32 * size) as i32, (options.margin + y as u32 * size) as i32)
+                    .of_size(size, size);
+                draw_filled_rect_mut(&mut img, rect, options.foreground);
+            }
+        }
+    }
+
+    Ok(img)
+}
+
+pub fn draw_tiles(grid: &Grid, path: &Path, options: &RenderOptions) -> ImageResult<()> {
+    render_tiles(grid, options)?.save(path)
+}
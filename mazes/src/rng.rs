@@ -0,0 +1,42 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+// ChaCha8 has a fixed, documented output stream, so the same seed produces the
+// same maze on every platform and across rand releases (unlike `StdRng`).
+pub type MazeRng = ChaCha8Rng;
+
+pub fn seeded(seed: u64) -> MazeRng {
+    MazeRng::seed_from_u64(seed)
+}
+
+// Seed from a command line argument, or a fresh random one when none was given.
+// A malformed argument is an error rather than a silent random seed, so a typo
+// can't be mistaken for a reproduction of the recorded maze.
+pub fn parse_seed(arg: Option<&str>) -> Result<u64, String> {
+    match arg {
+        Some(arg) => arg.parse().map_err(|_| format!("invalid seed '{}', expected an unsigned integer", arg)),
+        None => Ok(rand::random()),
+    }
+}
+
+// Sampling a `usize` range directly depends on the platform's pointer width,
+// so go through `u64` to keep the stream identical on 32 and 64 bit targets.
+pub fn random_index<R: Rng + ?Sized>(rng: &mut R, len: usize) -> usize {
+    rng.gen_range(0..len as u64) as usize
+}
+
+pub fn choose<'a, T, R: Rng + ?Sized>(rng: &mut R, items: &'a [T]) -> Option<&'a T> {
+    if items.is_empty() {
+        None
+    } else {
+        Some(&items[random_index(rng, items.len())])
+    }
+}
+
+// Fisher-Yates using `random_index`, for the same portability reason.
+pub fn shuffle<T, R: Rng + ?Sized>(rng: &mut R, items: &mut [T]) {
+    for i in (1..items.len()).rev() {
+        let j = random_index(rng, i + 1);
+        items.swap(i, j);
+    }
+}
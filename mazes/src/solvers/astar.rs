@@ -0,0 +1,71 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::grid::*;
+use crate::solvers::Solution;
+
+// Estimate of the cost from one cell to another. A* only returns the cheapest
+// path when the estimate never exceeds the real cost.
+pub trait Heuristic {
+    fn estimate(&self, from: &Coordinates, to: &Coordinates) -> usize;
+}
+
+// Rows plus columns apart; admissible on square grids with cell weights >= 1.
+pub struct Manhattan;
+
+impl Heuristic for Manhattan {
+    fn estimate(&self, from: &Coordinates, to: &Coordinates) -> usize {
+        ((from.row - to.row).abs() + (from.col - to.col).abs()) as usize
+    }
+}
+
+pub fn astar(grid: &Grid, start: &Coordinates, goal: &Coordinates) -> Option<Solution> {
+    astar_with(grid, start, goal, &Manhattan)
+}
+
+// Stepping into a cell costs its `Cell::weight`, as in `Distances::weighted`.
+// `visited` lists cells in the order they were expanded.
+pub fn astar_with<H: Heuristic + ?Sized>(grid: &Grid, start: &Coordinates, goal: &Coordinates, heuristic: &H) -> Option<Solution> {
+    let start_idx = grid.get_index(start)?;
+    let goal_idx = grid.get_index(goal)?;
+
+    let mut costs: Vec<Option<usize>> = vec![None; grid.size()];
+    let mut came_from: Vec<Option<usize>> = vec![None; grid.size()];
+    let mut closed = vec![false; grid.size()];
+    let mut visited = Vec::new();
+    let mut open = BinaryHeap::new();
+
+    costs[start_idx] = Some(0);
+    open.push(Reverse((heuristic.estimate(start, goal), 0, start_idx)));
+
+    while let Some(Reverse((_, cost, idx))) = open.pop() {
+        if closed[idx] {
+            continue;
+        }
+        closed[idx] = true;
+        visited.push(grid.grid[idx].coordinates.clone());
+
+        if idx == goal_idx {
+            let mut path = vec![goal.clone()];
+            let mut current = idx;
+            while let Some(previous) = came_from[current] {
+                path.push(grid.grid[previous].coordinates.clone());
+                current = previous;
+            }
+            path.reverse();
+            return Some(Solution { path, visited });
+        }
+
+        for link in grid.grid[idx].neighbors().iter().filter(|n| grid.grid[idx].links.contains(n)) {
+            let link_idx = grid.get_index(link).unwrap();
+            let link_cost = cost + grid.grid[link_idx].weight;
+            if !closed[link_idx] && costs[link_idx].is_none_or(|best| link_cost < best) {
+                costs[link_idx] = Some(link_cost);
+                came_from[link_idx] = Some(idx);
+                open.push(Reverse((link_cost + heuristic.estimate(link, goal), link_cost, link_idx)));
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,75 @@
+use std::collections::VecDeque;
+
+use crate::grid::*;
+use crate::solvers::Solution;
+
+// Fills in every dead end other than the start and goal, and keeps filling the
+// cells that become dead ends as a result. What is left unfilled contains the
+// solution (and, in mazes with loops, the loops that touch it). `visited` lists
+// the filled cells in order; `path` is the shortest route through what's left.
+pub fn dead_end_filling(grid: &Grid, start: &Coordinates, goal: &Coordinates) -> Option<Solution> {
+    let start_idx = grid.get_index(start)?;
+    let goal_idx = grid.get_index(goal)?;
+
+    let mut open_links = grid.grid.iter().map(|cell| cell.links.len()).collect::<Vec<_>>();
+    let mut filled = vec![false; grid.size()];
+    let mut visited = Vec::new();
+
+    let mut dead_ends = grid.grid
+        .iter()
+        .enumerate()
+        .filter(|&(idx, cell)| cell.links.len() <= 1 && idx != start_idx && idx != goal_idx)
+        .map(|(idx, _)| idx)
+        .collect::<VecDeque<_>>();
+
+    while let Some(idx) = dead_ends.pop_front() {
+        filled[idx] = true;
+        visited.push(grid.grid[idx].coordinates.clone());
+
+        for link in grid.grid[idx].neighbors().iter().filter(|n| grid.grid[idx].links.contains(n)) {
+            let link_idx = grid.get_index(link).unwrap();
+            if filled[link_idx] {
+                continue;
+            }
+            open_links[link_idx] -= 1;
+            if open_links[link_idx] == 1 && link_idx != start_idx && link_idx != goal_idx {
+                dead_ends.push_back(link_idx);
+            }
+        }
+    }
+
+    let path = unfilled_route(grid, &filled, start_idx, goal_idx)?;
+    Some(Solution { path, visited })
+}
+
+fn unfilled_route(grid: &Grid, filled: &[bool], start_idx: usize, goal_idx: usize) -> Option<Vec<Coordinates>> {
+    let mut came_from: Vec<Option<usize>> = vec![None; grid.size()];
+    let mut reached = vec![false; grid.size()];
+    let mut frontier = VecDeque::new();
+    reached[start_idx] = true;
+    frontier.push_back(start_idx);
+
+    while let Some(idx) = frontier.pop_front() {
+        if idx == goal_idx {
+            let mut path = vec![grid.grid[idx].coordinates.clone()];
+            let mut current = idx;
+            while let Some(previous) = came_from[current] {
+                path.push(grid.grid[previous].coordinates.clone());
+                current = previous;
+            }
+            path.reverse();
+            return Some(path);
+        }
+
+        for link in grid.grid[idx].neighbors().iter().filter(|n| grid.grid[idx].links.contains(n)) {
+            let link_idx = grid.get_index(link).unwrap();
+            if !filled[link_idx] && !reached[link_idx] {
+                reached[link_idx] = true;
+                came_from[link_idx] = Some(idx);
+                frontier.push_back(link_idx);
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,19 @@
+use crate::grid::Coordinates;
+
+pub mod astar;
+pub mod dead_end_filling;
+pub mod tremaux;
+pub mod wall_follower;
+
+pub use self::astar::{astar, astar_with, Heuristic, Manhattan};
+pub use self::dead_end_filling::dead_end_filling;
+pub use self::tremaux::tremaux;
+pub use self::wall_follower::{wall_follower, Hand};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution {
+    // From the start to the goal, both included.
+    pub path: Vec<Coordinates>,
+    // Every cell the solver looked at, in the order it got to them.
+    pub visited: Vec<Coordinates>,
+}
@@ -0,0 +1,54 @@
+use crate::grid::*;
+use crate::solvers::Solution;
+
+// Trémaux's algorithm: every passage is marked each time it is walked and is
+// never walked more than twice. Entering a cell that was already visited
+// through a fresh passage means a loop, so turn back. Otherwise prefer
+// unmarked passages, then the one marked once (the way back). Passages are
+// tried in `Direction::ALL` order, so the result is deterministic.
+pub fn tremaux(grid: &Grid, start: &Coordinates, goal: &Coordinates) -> Option<Solution> {
+    grid.get_index(start)?;
+    grid.get_index(goal)?;
+
+    // Marks on each passage, indexed like `Grid::grid` then by `Direction::ALL`.
+    let mut marks = vec![[0u8; 4]; grid.size()];
+    let mut entered = vec![false; grid.size()];
+    let mut path = LoopErasedPath::new(grid);
+    let mut visited = Vec::new();
+    let mut current = start.clone();
+    let mut back: Option<Direction> = None;
+
+    loop {
+        visited.push(current.clone());
+        path.push(grid, &current);
+        if current == *goal {
+            return Some(Solution { path: path.into_path(), visited });
+        }
+
+        let idx = grid.get_index(&current).unwrap();
+        let cell = &grid.grid[idx];
+        let seen_before = entered[idx];
+        entered[idx] = true;
+
+        let open = Direction::ALL
+            .iter()
+            .cloned()
+            .filter(|&d| cell.is_linked(cell.neighbor(d)))
+            .collect::<Vec<_>>();
+        let marks_on = |d: Direction| marks[idx][d.index()];
+
+        let next = match back {
+            Some(back) if seen_before && marks_on(back) == 1 => Some(back),
+            _ => open.iter().cloned().find(|&d| Some(d) != back && marks_on(d) == 0)
+                .or_else(|| back.filter(|&b| marks_on(b) < 2))
+                .or_else(|| open.iter().cloned().find(|&d| marks_on(d) == 1)),
+        }?;
+
+        let next_pos = cell.neighbor(next).clone().unwrap();
+        marks[idx][next.index()] += 1;
+        marks[grid.get_index(&next_pos).unwrap()][next.opposite().index()] += 1;
+
+        back = Some(next.opposite());
+        current = next_pos;
+    }
+}
@@ -0,0 +1,61 @@
+use crate::grid::*;
+use crate::solvers::Solution;
+
+// The hand kept on the wall.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hand {
+    Left,
+    Right,
+}
+
+// Walks with one hand on the wall until reaching the goal. `visited` is the
+// whole walk including dead ends and backtracking; `path` is the walk with
+// loops erased. Returns `None` if the walk gets back to a position it already
+// had, which happens in mazes with loops when the goal isn't on the same wall
+// as the start.
+pub fn wall_follower(grid: &Grid, start: &Coordinates, goal: &Coordinates, hand: Hand) -> Option<Solution> {
+    grid.get_index(start)?;
+    grid.get_index(goal)?;
+
+    // Whether the cell has been left in each direction, indexed by `Direction::ALL`.
+    let mut seen = vec![[false; 4]; grid.size()];
+    let mut path = LoopErasedPath::new(grid);
+    let mut visited = Vec::new();
+    let mut current = start.clone();
+    let turn_to_hand = |d: Direction| match hand {
+        Hand::Left => d.turn_left(),
+        Hand::Right => d.turn_right(),
+    };
+
+    // Start with the hand on a wall of the start cell.
+    let start_cell = grid.get(start).unwrap();
+    let mut facing = Direction::ALL
+        .iter()
+        .cloned()
+        .find(|&d| !start_cell.is_linked(start_cell.neighbor(turn_to_hand(d))))
+        .unwrap_or(Direction::North);
+
+    loop {
+        visited.push(current.clone());
+        path.push(grid, &current);
+        if current == *goal {
+            return Some(Solution { path: path.into_path(), visited });
+        }
+
+        let cell = grid.get(&current).unwrap();
+        let towards_hand = turn_to_hand(facing);
+        let next = [towards_hand, facing, towards_hand.opposite(), facing.opposite()]
+            .iter()
+            .cloned()
+            .find(|&d| cell.is_linked(cell.neighbor(d)))?;
+
+        let idx = grid.get_index(&current).unwrap();
+        if seen[idx][next.index()] {
+            return None;
+        }
+        seen[idx][next.index()] = true;
+
+        facing = next;
+        current = cell.neighbor(next).clone().unwrap();
+    }
+}
@@ -0,0 +1,83 @@
+use mazes::distances::{longest_path, Distances};
+use mazes::grid::*;
+
+fn link_path(grid: &mut Grid, cells: &[(isize, isize)]) {
+    for pair in cells.windows(2) {
+        grid.link(Coordinates::new(pair[0].0, pair[0].1), Coordinates::new(pair[1].0, pair[1].1));
+    }
+}
+
+// +---+---+---+
+// |           |
+// +   +---+---+
+// |   |       |
+// +   +---+   +
+// |           |
+// +---+---+---+
+//
+// One corridor from (0, 2) to (1, 1), passing through (0, 0) two steps in, so
+// the first cell isn't an end of the longest path.
+fn spiral() -> Grid {
+    let mut grid = Grid::new(3, 3);
+    link_path(&mut grid, &[(0, 2), (0, 1), (0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (1, 2), (1, 1)]);
+    grid
+}
+
+#[test]
+fn max_is_the_farthest_cell() {
+    let grid = spiral();
+    let distances = Distances::new(&grid, &Coordinates::new(0, 0)).unwrap();
+    assert_eq!(distances.max(), (Coordinates::new(1, 1), 6));
+    assert_eq!(distances.get(&Coordinates::new(0, 2)), Some(2));
+}
+
+#[test]
+fn longest_path_spans_the_maze() {
+    let grid = spiral();
+    let longest = longest_path(&grid).unwrap();
+    assert_eq!(longest.start, Coordinates::new(1, 1));
+    assert_eq!(longest.goal, Coordinates::new(0, 2));
+    assert_eq!(longest.path.len() - 1, 8);
+    assert_eq!(longest.path.first(), Some(&longest.start));
+    assert_eq!(longest.path.last(), Some(&longest.goal));
+}
+
+#[test]
+fn longest_path_of_a_corridor_visits_every_cell() {
+    // Rows joined alternately at the east and west ends, ending at (3, 0).
+    let mut grid = Grid::new(4, 5);
+    let mut cells = Vec::new();
+    for row in 0..4 {
+        for col in 0..5 {
+            cells.push((row, if row % 2 == 0 { col } else { 4 - col }));
+        }
+    }
+    link_path(&mut grid, &cells);
+
+    let longest = longest_path(&grid).unwrap();
+    assert_eq!(longest.start, Coordinates::new(3, 0));
+    assert_eq!(longest.goal, Coordinates::new(0, 0));
+    assert_eq!(longest.path.len(), grid.size());
+}
+
+#[test]
+fn empty_grid_has_no_longest_path() {
+    assert!(longest_path(&Grid::new(0, 0)).is_none());
+    assert!(longest_path(&Grid::new(0, 4)).is_none());
+}
+
+#[test]
+fn weighted_path_through_free_cells() {
+    let mut grid = Grid::new(1, 4);
+    link_path(&mut grid, &[(0, 0), (0, 1), (0, 2), (0, 3)]);
+    grid.grid[1].weight = 0;
+    grid.grid[2].weight = 0;
+
+    let start = Coordinates::new(0, 0);
+    let goal = Coordinates::new(0, 3);
+    let distances = Distances::weighted(&grid, &start).unwrap();
+    assert_eq!(distances.get(&Coordinates::new(0, 2)), Some(0));
+    assert_eq!(distances.get(&goal), Some(1));
+    assert_eq!(distances.path_to(&grid, &goal).unwrap(),
+               vec![start, Coordinates::new(0, 1), Coordinates::new(0, 2), goal]);
+}
@@ -0,0 +1,120 @@
+use mazes::algorithms::kruskals::{kruskals_with_sets, link_if_disjoint, UnionFind};
+use mazes::algorithms::*;
+use mazes::grid::*;
+use mazes::rng;
+
+use std::collections::VecDeque;
+
+fn assert_perfect(grid: &Grid, name: &str) {
+    for cell in &grid.grid {
+        for link in &cell.links {
+            let other = grid.get(link).unwrap_or_else(|| panic!("{}: link to {:?} is outside the grid", name, link));
+            assert!(other.links.contains(&cell.coordinates), "{}: {:?} -> {:?} isn't symmetric", name, cell.coordinates, link);
+            assert!(cell.neighbors().contains(link), "{}: {:?} is linked to non-neighbor {:?}", name, cell.coordinates, link);
+        }
+    }
+
+    let links = grid.grid.iter().map(|cell| cell.links.len()).sum::<usize>() / 2;
+    assert_eq!(links, grid.size() - 1, "{}: a perfect maze has one link fewer than cells", name);
+
+    let mut reached = vec![false; grid.size()];
+    let mut frontier = VecDeque::new();
+    reached[0] = true;
+    frontier.push_back(grid.grid[0].coordinates.clone());
+    while let Some(pos) = frontier.pop_front() {
+        for link in &grid.get(&pos).unwrap().links {
+            let idx = grid.get_index(link).unwrap();
+            if !reached[idx] {
+                reached[idx] = true;
+                frontier.push_back(link.clone());
+            }
+        }
+    }
+    assert!(reached.iter().all(|&r| r), "{}: maze isn't connected", name);
+}
+
+#[test]
+fn every_generator_makes_a_perfect_maze() {
+    for generator in generators() {
+        for &(rows, cols) in &[(1, 1), (1, 9), (9, 1), (2, 2), (8, 13), (17, 11)] {
+            for seed in 0..10 {
+                let mut grid = Grid::new(rows, cols);
+                generate_seeded(generator.as_ref(), &mut grid, seed);
+                assert_perfect(&grid, &format!("{} {}x{} seed {}", generator.name(), rows, cols, seed));
+            }
+        }
+    }
+}
+
+#[test]
+fn configured_generators_make_perfect_mazes() {
+    let mut configured: Vec<Box<dyn MazeGenerator>> = Vec::new();
+    for &diagonal in &[Diagonal::NorthEast, Diagonal::NorthWest, Diagonal::SouthEast, Diagonal::SouthWest] {
+        configured.push(Box::new(BinaryTree { diagonal, vertical_probability: 0.3 }));
+    }
+    for &(run, close) in &[(Direction::East, Direction::North), (Direction::West, Direction::South),
+                           (Direction::North, Direction::East), (Direction::South, Direction::West)] {
+        configured.push(Box::new(Sidewinder { run, close, continue_probability: 0.7, max_run_length: Some(3) }));
+    }
+    for &selection in &[CellSelection::Newest, CellSelection::Oldest, CellSelection::Random, CellSelection::Mix { newest: 0.2 }] {
+        configured.push(Box::new(GrowingTree { selection }));
+    }
+
+    for generator in configured {
+        for &(rows, cols) in &[(1, 1), (1, 7), (7, 1), (9, 12)] {
+            for seed in 0..5 {
+                let mut grid = Grid::new(rows, cols);
+                generate_seeded(generator.as_ref(), &mut grid, seed);
+                assert_perfect(&grid, &format!("{} {}x{} seed {}", generator.name(), rows, cols, seed));
+            }
+        }
+    }
+}
+
+#[test]
+fn kruskals_keeps_forced_passages() {
+    // A U-shaped room in the middle; closing it into a square would make a loop.
+    let room = [(2, 2), (2, 3), (3, 3), (3, 2)];
+    for seed in 0..10 {
+        let mut grid = Grid::new(6, 7);
+        let mut sets = UnionFind::new(grid.size());
+        for pair in room.windows(2) {
+            let (pos1, pos2) = (Coordinates::new(pair[0].0, pair[0].1), Coordinates::new(pair[1].0, pair[1].1));
+            assert!(link_if_disjoint(&mut grid, &mut sets, pos1, pos2));
+        }
+        assert!(!link_if_disjoint(&mut grid, &mut sets, Coordinates::new(3, 2), Coordinates::new(2, 2)));
+        assert!(!grid.get(&Coordinates::new(3, 2)).unwrap().links.contains(&Coordinates::new(2, 2)));
+
+        kruskals_with_sets(&mut grid, &mut sets, &mut rng::seeded(seed));
+        for pair in room.windows(2) {
+            let cell = grid.get(&Coordinates::new(pair[0].0, pair[0].1)).unwrap();
+            assert!(cell.links.contains(&Coordinates::new(pair[1].0, pair[1].1)), "seed {}: forced passage lost", seed);
+        }
+        assert_perfect(&grid, &format!("kruskals with a forced room, seed {}", seed));
+    }
+}
+
+#[test]
+fn kruskals_builds_around_passages_already_on_the_grid() {
+    for seed in 0..10 {
+        let mut grid = Grid::new(5, 5);
+        grid.link(Coordinates::new(0, 0), Coordinates::new(0, 1));
+        grid.link(Coordinates::new(4, 4), Coordinates::new(3, 4));
+        let mut sets = UnionFind::from_grid(&grid);
+        assert!(sets.connected(0, 1));
+
+        kruskals_with_sets(&mut grid, &mut sets, &mut rng::seeded(seed));
+        assert!(grid.get(&Coordinates::new(0, 0)).unwrap().links.contains(&Coordinates::new(0, 1)));
+        assert!(grid.get(&Coordinates::new(4, 4)).unwrap().links.contains(&Coordinates::new(3, 4)));
+        assert_perfect(&grid, &format!("kruskals from pre-linked grid, seed {}", seed));
+    }
+}
+
+#[test]
+fn registry_finds_generators_by_name() {
+    for name in generator_names() {
+        assert_eq!(find_generator(name).unwrap().name(), name);
+        assert_eq!(find_generator(&name.to_uppercase()).unwrap().name(), name);
+    }
+    assert!(find_generator("no_such_algorithm").is_none());
+}
@@ -0,0 +1,42 @@
+use mazes::algorithms::*;
+use mazes::grid::*;
+use mazes::rng;
+
+fn perfect_maze(rows: isize, cols: isize, seed: u64) -> Grid {
+    let mut grid = Grid::new(rows, cols);
+    generate_seeded(&RecursiveBacktracker, &mut grid, seed);
+    grid
+}
+
+#[test]
+fn full_braid_removes_every_dead_end() {
+    for &(rows, cols) in &[(2, 2), (5, 9), (12, 7)] {
+        for seed in 0..10 {
+            let before = perfect_maze(rows, cols, seed);
+            let mut grid = perfect_maze(before.rows, before.cols, seed);
+            grid.braid(1.0, &mut rng::seeded(seed));
+            assert!(grid.dead_ends().is_empty(), "{}x{} seed {}:\n{}", rows, cols, seed, grid);
+
+            // Braiding only adds passages, each between neighbors, from both sides.
+            for (old, new) in before.grid.iter().zip(&grid.grid) {
+                assert!(old.links.is_subset(&new.links));
+                for link in new.links.difference(&old.links) {
+                    assert!(new.neighbors().contains(link), "{:?} linked to non-neighbor {:?}", new.coordinates, link);
+                    assert!(grid.get(link).unwrap().links.contains(&new.coordinates));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn zero_braid_changes_nothing() {
+    for seed in 0..10 {
+        let before = perfect_maze(8, 8, seed);
+        let mut grid = perfect_maze(before.rows, before.cols, seed);
+        grid.braid(0.0, &mut rng::seeded(seed));
+        for (old, new) in before.grid.iter().zip(&grid.grid) {
+            assert_eq!(old.links, new.links);
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use image::{Rgb, RgbImage};
+
+use mazes::distances::Distances;
+use mazes::grid::*;
+use mazes::render::png::{render, RenderOptions};
+
+use std::env;
+use std::path::PathBuf;
+
+// A maze that doesn't depend on any generator or RNG: every row is one
+// corridor, joined to the next one alternately at the east and west ends.
+fn serpentine(rows: isize, cols: isize) -> Grid {
+    let mut grid = Grid::new(rows, cols);
+    for row in 0..rows {
+        for col in 0..cols - 1 {
+            grid.link(Coordinates::new(row, col), Coordinates::new(row, col + 1));
+        }
+        if row < rows - 1 {
+            let col = if row % 2 == 0 { cols - 1 } else { 0 };
+            grid.link(Coordinates::new(row, col), Coordinates::new(row + 1, col));
+        }
+    }
+    grid
+}
+
+// Compares against tests/golden/<name>. Run with UPDATE_GOLDEN=1 to rewrite
+// the golden files after an intended change to the renderer.
+fn assert_golden(name: &str, img: &RgbImage) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden").join(name);
+    if env::var_os("UPDATE_GOLDEN").is_some() {
+        img.save(&path).unwrap();
+        return;
+    }
+
+    let golden = image::open(&path).unwrap_or_else(|e| panic!("can't open {}: {}", path.display(), e)).to_rgb8();
+    if golden != *img {
+        let actual = env::temp_dir().join(format!("actual_{}", name));
+        img.save(&actual).unwrap();
+        panic!("{} doesn't match its golden image, see {}", name, actual.display());
+    }
+}
+
+fn assert_border_closed(img: &RgbImage, options: &RenderOptions) {
+    let first = options.margin;
+    let (last_x, last_y) = (img.width() - options.margin - 1, img.height() - options.margin - 1);
+    for x in first..=last_x {
+        assert_eq!(*img.get_pixel(x, first), options.foreground, "top border at x = {}", x);
+        assert_eq!(*img.get_pixel(x, last_y), options.foreground, "bottom border at x = {}", x);
+    }
+    for y in first..=last_y {
+        assert_eq!(*img.get_pixel(first, y), options.foreground, "left border at y = {}", y);
+        assert_eq!(*img.get_pixel(last_x, y), options.foreground, "right border at y = {}", y);
+    }
+}
+
+#[test]
+fn square_grid() {
+    let options = RenderOptions::default();
+    let img = render(&serpentine(5, 5), &options, None, None).unwrap();
+    assert_eq!(img.dimensions(), (5 * 31 + 1, 5 * 31 + 1));
+    assert_border_closed(&img, &options);
+    assert_golden("square_5x5.png", &img);
+}
+
+#[test]
+fn wide_grid() {
+    let options = RenderOptions::default();
+    let img = render(&serpentine(3, 7), &options, None, None).unwrap();
+    assert_eq!(img.dimensions(), (7 * 31 + 1, 3 * 31 + 1));
+    assert_border_closed(&img, &options);
+    assert_golden("wide_3x7.png", &img);
+}
+
+#[test]
+fn tall_grid() {
+    let options = RenderOptions::default();
+    let img = render(&serpentine(7, 3), &options, None, None).unwrap();
+    assert_eq!(img.dimensions(), (3 * 31 + 1, 7 * 31 + 1));
+    assert_border_closed(&img, &options);
+    assert_golden("tall_7x3.png", &img);
+}
+
+#[test]
+fn walls_follow_links_on_non_square_grid() {
+    let options = RenderOptions::default();
+    let grid = serpentine(3, 7);
+    let img = render(&grid, &options, None, None).unwrap();
+
+    // Row 0 turns south at its east end, row 1 at its west end.
+    let wall_below = |row: u32, col: u32| *img.get_pixel(col * 31 + 16, (row + 1) * 31);
+    assert_eq!(wall_below(0, 6), options.background);
+    assert_eq!(wall_below(0, 0), options.foreground);
+    assert_eq!(wall_below(1, 0), options.background);
+    assert_eq!(wall_below(1, 6), options.foreground);
+
+    // No wall between cells of the same row, and nothing drawn inside cells.
+    for row in 0..3 {
+        for col in 0..7 {
+            assert_eq!(*img.get_pixel(col * 31 + 16, row * 31 + 16), options.background);
+            if col < 6 {
+                assert_eq!(*img.get_pixel((col + 1) * 31, row * 31 + 16), options.background);
+            }
+        }
+    }
+}
+
+#[test]
+fn styled_grid_with_route_and_heat_map() {
+    let options = RenderOptions {
+        cell_size: 12,
+        wall_thickness: 3,
+        margin: 5,
+        foreground: Rgb([20, 20, 20]),
+        background: Rgb([250, 250, 240]),
+        antialias: false,
+    };
+    let grid = serpentine(4, 6);
+    let start = Coordinates::new(0, 0);
+    let distances = Distances::new(&grid, &start).unwrap();
+    let route = distances.path_to(&grid, &Coordinates::new(3, 0)).unwrap();
+
+    let img = render(&grid, &options, Some(&route), Some(&distances)).unwrap();
+    assert_eq!(img.dimensions(), (2 * 5 + 6 * 15 + 3, 2 * 5 + 4 * 15 + 3));
+    assert_border_closed(&img, &options);
+    assert_golden("styled_4x6.png", &img);
+}
+
+#[test]
+fn rejects_routes_that_leave_the_grid_or_skip_cells() {
+    let grid = serpentine(3, 3);
+    let options = RenderOptions::default();
+    let outside = [Coordinates::new(0, 0), Coordinates::new(-1, 0)];
+    let diagonal = [Coordinates::new(0, 0), Coordinates::new(1, 1)];
+    let jump = [Coordinates::new(0, 0), Coordinates::new(0, 2)];
+
+    for route in [&outside[..], &diagonal[..], &jump[..]] {
+        assert!(render(&grid, &options, Some(route), None).is_err(), "{:?}", route);
+    }
+    assert!(render(&grid, &options, Some(&[Coordinates::new(1, 1)]), None).is_ok());
+}
+
+#[test]
+fn zero_sizes_are_errors() {
+    let grid = serpentine(2, 2);
+    for options in [
+        RenderOptions { cell_size: 0, ..RenderOptions::default() },
+        RenderOptions { wall_thickness: 0, ..RenderOptions::default() },
+        RenderOptions { cell_size: 0, antialias: true, ..RenderOptions::default() },
+    ] {
+        assert!(render(&grid, &options, None, None).is_err(), "{:?}", options);
+    }
+}
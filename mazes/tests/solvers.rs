@@ -0,0 +1,95 @@
+use mazes::algorithms::*;
+use mazes::distances::Distances;
+use mazes::grid::*;
+use mazes::rng;
+use mazes::solvers::*;
+
+use rand::Rng;
+
+// Sum of the weights of every cell entered, checking each step is a passage.
+fn cost(grid: &Grid, path: &[Coordinates]) -> usize {
+    for step in path.windows(2) {
+        assert!(grid.get(&step[0]).unwrap().is_linked(&Some(step[1].clone())), "{:?} -> {:?} isn't a passage", step[0], step[1]);
+    }
+    path[1..].iter().map(|pos| grid.get(pos).unwrap().weight).sum()
+}
+
+#[test]
+fn astar_finds_the_cheapest_path() {
+    for seed in 0..20 {
+        let mut grid = Grid::new(12, 15);
+        generate_seeded(&RecursiveBacktracker, &mut grid, seed);
+        let mut rng = rng::seeded(seed);
+        grid.braid(0.5, &mut rng);
+        for cell in grid.grid.iter_mut() {
+            cell.weight = rng.gen_range(1..=9);
+        }
+
+        let start = Coordinates::new(0, 0);
+        let goal = Coordinates::new(11, 14);
+        let distances = Distances::weighted(&grid, &start).unwrap();
+        let solution = astar(&grid, &start, &goal).unwrap();
+
+        assert_eq!(solution.path.first(), Some(&start));
+        assert_eq!(solution.path.last(), Some(&goal));
+        assert_eq!(Some(cost(&grid, &solution.path)), distances.get(&goal), "seed {}", seed);
+    }
+}
+
+// A perfect maze has exactly one path between two cells, so every solver has
+// to come back with the one breadth-first search finds.
+#[test]
+fn solvers_find_the_only_path_in_perfect_mazes() {
+    for generator in generators() {
+        for seed in 0..5 {
+            let mut grid = Grid::new(10, 12);
+            generate_seeded(generator.as_ref(), &mut grid, seed);
+
+            let start = Coordinates::new(0, 0);
+            let goal = Coordinates::new(9, 11);
+            let expected = Distances::new(&grid, &start).unwrap().path_to(&grid, &goal).unwrap();
+            let context = format!("{} with seed {}", generator.name(), seed);
+
+            for hand in [Hand::Left, Hand::Right] {
+                let solution = wall_follower(&grid, &start, &goal, hand).unwrap();
+                assert_eq!(solution.path, expected, "{:?} hand, {}", hand, context);
+            }
+            assert_eq!(tremaux(&grid, &start, &goal).unwrap().path, expected, "tremaux, {}", context);
+            assert_eq!(dead_end_filling(&grid, &start, &goal).unwrap().path, expected, "dead end filling, {}", context);
+            assert_eq!(astar(&grid, &start, &goal).unwrap().path, expected, "astar, {}", context);
+        }
+    }
+}
+
+// +---+---+---+---+
+// |               |
+// +   +---+---+   +
+// |           |   |
+// +   +---+   +   +
+// |   | G     |   |
+// +   +---+---+   +
+// |               |
+// +---+---+---+---+
+//
+// The walls around the goal aren't connected to the outer wall, so a hand on
+// the outer wall circles the ring forever and never gets to the goal.
+#[test]
+fn wall_follower_gives_up_when_the_goal_is_on_a_detached_wall() {
+    let mut grid = Grid::new(4, 4);
+    let ring = [(0, 0), (0, 1), (0, 2), (0, 3), (1, 3), (2, 3), (3, 3), (3, 2), (3, 1), (3, 0), (2, 0), (1, 0)];
+    for (i, &(row, col)) in ring.iter().enumerate() {
+        let (next_row, next_col) = ring[(i + 1) % ring.len()];
+        grid.link(Coordinates::new(row, col), Coordinates::new(next_row, next_col));
+    }
+    let island = [(1, 0), (1, 1), (1, 2), (2, 2), (2, 1)];
+    for step in island.windows(2) {
+        grid.link(Coordinates::new(step[0].0, step[0].1), Coordinates::new(step[1].0, step[1].1));
+    }
+
+    let start = Coordinates::new(0, 0);
+    let goal = Coordinates::new(2, 1);
+    assert!(Distances::new(&grid, &start).unwrap().get(&goal).is_some());
+    assert!(wall_follower(&grid, &start, &goal, Hand::Left).is_none());
+    assert!(wall_follower(&grid, &start, &goal, Hand::Right).is_none());
+    assert!(tremaux(&grid, &start, &goal).is_some());
+}
@@ -0,0 +1,36 @@
+use mazes::grid::*;
+use mazes::render::svg::{to_svg, SvgOptions};
+
+// +---+---+---+
+// |           |
+// +   +---+   +
+// |       |   |
+// +---+---+---+
+fn small_maze() -> Grid {
+    let mut grid = Grid::new(2, 3);
+    grid.link(Coordinates::new(0, 0), Coordinates::new(0, 1));
+    grid.link(Coordinates::new(0, 1), Coordinates::new(0, 2));
+    grid.link(Coordinates::new(0, 0), Coordinates::new(1, 0));
+    grid.link(Coordinates::new(1, 0), Coordinates::new(1, 1));
+    grid.link(Coordinates::new(0, 2), Coordinates::new(1, 2));
+    grid
+}
+
+#[test]
+fn walls_merge_into_straight_runs() {
+    let options = SvgOptions { cell_size: 10.0, stroke_width: 1.0, margin: 2.0 };
+    let expected = concat!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="34" height="24" viewBox="0 0 34 24">"#, "\n",
+        // Horizontal runs line by line, then vertical runs column by column.
+        r#"  <path d="M2 2H32M12 12H22M2 22H32M2 2V22M22 12V22M32 2V22" fill="none" stroke="black" stroke-width="1" stroke-linecap="square"/>"#, "\n",
+        "</svg>\n",
+    );
+    assert_eq!(to_svg(&small_maze(), &options), expected);
+}
+
+#[test]
+fn size_includes_margin_on_both_sides() {
+    let options = SvgOptions { cell_size: 7.5, stroke_width: 2.0, margin: 4.0 };
+    let svg = to_svg(&Grid::new(3, 5), &options);
+    assert!(svg.contains(r#"width="45.5" height="30.5" viewBox="0 0 45.5 30.5""#), "{}", svg);
+}
@@ -0,0 +1,44 @@
+use mazes::algorithms::ellers::ellers_rows;
+use mazes::algorithms::*;
+use mazes::grid::*;
+use mazes::render::text::{to_unicode, write_rows};
+use mazes::rng;
+
+#[test]
+fn unicode_junctions() {
+    let mut grid = Grid::new(2, 3);
+    grid.link(Coordinates::new(0, 0), Coordinates::new(0, 1));
+    grid.link(Coordinates::new(0, 1), Coordinates::new(0, 2));
+    grid.link(Coordinates::new(0, 2), Coordinates::new(1, 2));
+    grid.link(Coordinates::new(1, 2), Coordinates::new(1, 1));
+    grid.link(Coordinates::new(0, 0), Coordinates::new(1, 0));
+
+    let expected = [
+        "┌───────────┐",
+        "│           │",
+        "│   ┌───╴   │",
+        "│   │       │",
+        "└───┴───────┘",
+        "",
+    ].join("\n");
+    assert_eq!(to_unicode(&grid), expected);
+}
+
+#[test]
+fn unicode_single_cell() {
+    assert_eq!(to_unicode(&Grid::new(1, 1)), "┌───┐\n│   │\n└───┘\n");
+}
+
+#[test]
+fn streamed_rows_match_the_generated_grid() {
+    for &(rows, cols) in &[(1, 1), (1, 8), (8, 1), (12, 9)] {
+        for seed in 0..10 {
+            let mut out = Vec::new();
+            write_rows(&mut out, cols, ellers_rows(rows, cols, &mut rng::seeded(seed))).unwrap();
+
+            let mut grid = Grid::new(rows, cols);
+            generate_seeded(&Ellers, &mut grid, seed);
+            assert_eq!(String::from_utf8(out).unwrap(), grid.to_string(), "{}x{} seed {}", rows, cols, seed);
+        }
+    }
+}
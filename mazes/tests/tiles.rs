@@ -0,0 +1,45 @@
+use mazes::grid::*;
+use mazes::render::png::RenderOptions;
+use mazes::render::tiles::{render_tiles, to_tiles};
+
+fn parse(rows: &[&str]) -> Vec<Vec<bool>> {
+    rows.iter().map(|row| row.chars().map(|c| c == '#').collect()).collect()
+}
+
+#[test]
+fn tiles_follow_links() {
+    // Top row open along its length, then down the east side.
+    let mut grid = Grid::new(2, 3);
+    grid.link(Coordinates::new(0, 0), Coordinates::new(0, 1));
+    grid.link(Coordinates::new(0, 1), Coordinates::new(0, 2));
+    grid.link(Coordinates::new(0, 2), Coordinates::new(1, 2));
+    grid.link(Coordinates::new(1, 2), Coordinates::new(1, 1));
+
+    assert_eq!(to_tiles(&grid), parse(&[
+        "#######",
+        "#.....#",
+        "#####.#",
+        "#.#...#",
+        "#######",
+    ]));
+}
+
+#[test]
+fn rendered_tiles_are_filled_squares() {
+    let mut grid = Grid::new(1, 2);
+    grid.link(Coordinates::new(0, 0), Coordinates::new(0, 1));
+    let options = RenderOptions { cell_size: 4, margin: 2, ..RenderOptions::default() };
+
+    let img = render_tiles(&grid, &options).unwrap();
+    assert_eq!(img.dimensions(), (5 * 4 + 4, 3 * 4 + 4));
+    assert_eq!(*img.get_pixel(0, 0), options.background);
+    assert_eq!(*img.get_pixel(2, 2), options.foreground);
+    assert_eq!(*img.get_pixel(2 + 4 + 1, 2 + 4 + 1), options.background);
+    assert_eq!(*img.get_pixel(2 + 2 * 4 + 3, 2 + 4 + 3), options.background);
+}
+
+#[test]
+fn zero_cell_size_is_an_error() {
+    let options = RenderOptions { cell_size: 0, ..RenderOptions::default() };
+    assert!(render_tiles(&Grid::new(2, 2), &options).is_err());
+}